@@ -4,10 +4,49 @@ pub const DRIVER_PROFILE_SEED: &[u8] = b"driver_profile";
 pub const SESSION_SEED: &[u8] = b"session";
 pub const POINTS_VAULT_SEED: &[u8] = b"points_vault";
 pub const WORLD_PLOT_SEED: &[u8] = b"world_plot";
-pub const SESSION_COUNTER_SEED: &[u8] = b"session_counter";
+pub const DRIVER_DELEGATE_SEED: &[u8] = b"driver_delegate";
+pub const TELEMETRY_LOG_SEED: &[u8] = b"telemetry_log";
+pub const VEHICLE_SEED: &[u8] = b"vehicle";
+pub const SESSION_RECEIPT_SEED: &[u8] = b"session_receipt";
+pub const RECEIPT_MINT_SEED: &[u8] = b"receipt_mint";
+pub const REC_MINT_SEED: &[u8] = b"rec_mint";
+pub const OPERATOR_RECS_SEED: &[u8] = b"operator_recs";
+pub const CATALOG_ITEM_SEED: &[u8] = b"catalog_item";
+pub const ITEM_REDEMPTION_SEED: &[u8] = b"item_redemption";
+pub const POINT_EMISSIONS_SEED: &[u8] = b"point_emissions";
+pub const POINT_STAKE_SEED: &[u8] = b"point_stake";
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+pub const VOTE_RECORD_SEED: &[u8] = b"vote_record";
+pub const WORLD_CONFIG_SEED: &[u8] = b"world_config";
+pub const REGION_SEED: &[u8] = b"region";
+pub const PLOT_BOOST_SEED: &[u8] = b"plot_boost";
+pub const VIRTUAL_SESSION_SEED: &[u8] = b"virtual_session";
 
 pub const DEFAULT_POINTS_DECIMALS: u8 = 6;
+pub const REC_DECIMALS: u8 = 0;
 pub const MAX_NAME_LEN: usize = 64;
 pub const MAX_CITY_LEN: usize = 48;
 pub const MAX_URI_LEN: usize = 128;
+pub const MAX_SYMBOL_LEN: usize = 10;
 pub const MICROS_PER_POINT: u64 = 1_000_000;
+pub const TELEMETRY_LOG_CAPACITY: usize = 64;
+pub const MAX_POINT_BUCKETS: usize = 12;
+pub const MAX_LOYALTY_TIERS: usize = 3;
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const WH_PER_KWH: u64 = 1_000;
+pub const WH_PER_MWH: u64 = 1_000_000;
+pub const SECONDS_PER_DAY: i64 = 86_400;
+pub const MAX_STAKE_LOCK_SECONDS: i64 = 4 * 365 * SECONDS_PER_DAY;
+pub const MAX_PLOT_LEVEL: u8 = 3;
+pub const BOOST_KIND_COUNT: usize = 3;
+pub const SECONDS_PER_HOUR: i64 = 3_600;
+
+pub const DELEGATE_SCOPE_START_SESSION: u8 = 1 << 0;
+pub const DELEGATE_SCOPE_STOP_SESSION: u8 = 1 << 1;
+
+pub const CONNECTOR_TYPE_J1772: u8 = 1 << 0;
+pub const CONNECTOR_TYPE_TYPE2: u8 = 1 << 1;
+pub const CONNECTOR_TYPE_CCS1: u8 = 1 << 2;
+pub const CONNECTOR_TYPE_CCS2: u8 = 1 << 3;
+pub const CONNECTOR_TYPE_CHADEMO: u8 = 1 << 4;
+pub const CONNECTOR_TYPE_NACS: u8 = 1 << 5;
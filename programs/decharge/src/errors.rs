@@ -24,4 +24,94 @@ pub enum DechargeError {
     PlotOccupied,
     #[msg("Numerical overflow detected")] 
     NumericalOverflow,
+    #[msg("Driver delegate has expired")] 
+    DelegateExpired,
+    #[msg("Driver delegate is not scoped for this action")] 
+    DelegateScopeDenied,
+    #[msg("Driver delegate spending limit reached")] 
+    DelegateSpendExceeded,
+    #[msg("Vehicle does not match driver or session")] 
+    VehicleMismatch,
+    #[msg("Delivered energy exceeds vehicle battery capacity")] 
+    EnergyExceedsBattery,
+    #[msg("Charging power exceeds vehicle rate")] 
+    PowerExceedsVehicle,
+    #[msg("Basis point value exceeds 100%")] 
+    InvalidBasisPoints,
+    #[msg("Not enough renewable energy accrued to claim a certificate")] 
+    NothingToClaim,
+    #[msg("Catalog item is not available for redemption")] 
+    ItemUnavailable,
+    #[msg("Catalog item is out of stock")] 
+    ItemSoldOut,
+    #[msg("Per-wallet redemption limit reached")] 
+    RedemptionLimitReached,
+    #[msg("Points expiry horizon exceeds bucket capacity")] 
+    InvalidExpiryHorizon,
+    #[msg("Drivers cannot refer themselves")] 
+    SelfReferral,
+    #[msg("Points cannot be transferred to the same driver")] 
+    SelfTransfer,
+    #[msg("Daily point transfer limit exceeded")] 
+    DailyTransferLimitExceeded,
+    #[msg("Stake lock duration is out of range")] 
+    InvalidLockDuration,
+    #[msg("Staked points are still locked")] 
+    StakeLocked,
+    #[msg("Governance is disabled")] 
+    GovernanceDisabled,
+    #[msg("No voting power")] 
+    NoVotingPower,
+    #[msg("Stake must stay locked until voting ends")] 
+    StakeUnlocksBeforeVoteEnds,
+    #[msg("Voting has closed for this proposal")] 
+    VotingClosed,
+    #[msg("Voting is still open for this proposal")] 
+    VotingOpen,
+    #[msg("Proposal has already been executed")] 
+    ProposalAlreadyExecuted,
+    #[msg("Proposal did not pass")] 
+    ProposalNotPassed,
+    #[msg("Accounts required to pay the fee in points were not provided")] 
+    MissingFeeAccounts,
+    #[msg("Token account is not owned by the world treasury")] 
+    TreasuryMismatch,
+    #[msg("Region bounds are invalid")] 
+    InvalidRegionBounds,
+    #[msg("Plot lies outside its region bounds")] 
+    PlotOutOfBounds,
+    #[msg("Region has no plot slots left")] 
+    RegionFull,
+    #[msg("World plot supply is exhausted")] 
+    WorldFull,
+    #[msg("Plot cannot be upgraded to this level")] 
+    InvalidUpgradeLevel,
+    #[msg("Peak hours must fall within a day")] 
+    InvalidPeakHours,
+    #[msg("Boost duration must be positive")] 
+    InvalidBoostDuration,
+    #[msg("Plot has not been claimed")] 
+    PlotUnclaimed,
+    #[msg("Vehicle has no connector supported by the station")] 
+    ConnectorMismatch,
+    #[msg("Charging session is stopping and accepts no further telemetry")] 
+    SessionStopping,
+    #[msg("Redemption window must start before it ends")] 
+    InvalidRedemptionWindow,
+    #[msg("Points epoch length cannot change once set")] 
+    PointsEpochLocked,
+    #[msg("A per-epoch emission budget needs a points epoch length")] 
+    EmissionEpochRequired,
+    #[msg("World bounds are invalid")] 
+    InvalidWorldBounds,
+    #[msg("Plot supply cannot drop below the plots already defined")] 
+    PlotSupplyTooLow,
+    #[msg("Region must lie within the world bounds")] 
+    RegionOutOfBounds,
+    #[msg("Plot level prices must strictly increase")] 
+    InvalidLevelPrices,
+    #[msg("Point emissions account is required while an emission budget is set")] 
+    MissingEmissionsAccount,
+    #[msg("Receipt token accounts are required to issue a receipt")] 
+    MissingReceiptAccounts,
 }
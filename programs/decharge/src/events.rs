@@ -1,5 +1,15 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{BoostKind, GovernanceParam};
+
+#[event]
+pub struct PointMetadataUpdated {
+    pub point_mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
 #[event]
 pub struct StationRegistered {
     pub station: Pubkey,
@@ -7,6 +17,14 @@ pub struct StationRegistered {
     pub city: String,
 }
 
+#[event]
+pub struct VehicleRegistered {
+    pub vehicle: Pubkey,
+    pub owner: Pubkey,
+    pub battery_capacity_wh: u64,
+    pub connector_types: u8,
+}
+
 #[event]
 pub struct SessionStarted {
     pub session: Pubkey,
@@ -15,12 +33,95 @@ pub struct SessionStarted {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct TelemetryBatchRecorded {
+    pub session: Pubkey,
+    pub samples: u32,
+    pub energy_wh: u64,
+    pub last_timestamp: i64,
+}
+
+#[event]
+pub struct SessionStopRequested {
+    pub session: Pubkey,
+    pub driver: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TargetSocReached {
+    pub session: Pubkey,
+    pub soc_percent: u8,
+}
+
 #[event]
 pub struct SessionClosed {
     pub session: Pubkey,
     pub energy_wh: u64,
     pub seconds: u64,
     pub points_minted: u64,
+    pub start_soc_percent: Option<u8>,
+    pub end_soc_percent: Option<u8>,
+    pub co2_avoided_g: u64,
+    pub tier: u8,
+    pub points_deferred: u64,
+}
+
+#[event]
+pub struct EmissionBudgetWarning {
+    pub epoch: u64,
+    pub epoch_minted: u64,
+    pub epoch_budget: u64,
+    pub lifetime_minted: u64,
+    pub lifetime_budget: u64,
+}
+
+#[event]
+pub struct LoyaltyTierChanged {
+    pub driver: Pubkey,
+    pub previous_tier: u8,
+    pub tier: u8,
+}
+
+#[event]
+pub struct ReceiptIssued {
+    pub receipt: Pubkey,
+    pub mint: Pubkey,
+    pub session: Pubkey,
+    pub driver: Pubkey,
+    pub station: Pubkey,
+    pub energy_wh: u64,
+    pub price_microunits: u64,
+    pub telemetry_hash: [u8; 32],
+}
+
+#[event]
+pub struct ReferralRewarded {
+    pub referrer: Pubkey,
+    pub referee: Pubkey,
+    pub referrer_bonus: u64,
+    pub referee_bonus: u64,
+}
+
+#[event]
+pub struct PointsExpired {
+    pub driver: Pubkey,
+    pub amount: u64,
+    pub cutoff_epoch: u64,
+}
+
+#[event]
+pub struct PointsWithdrawn {
+    pub driver: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PointsTransferred {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
 }
 
 #[event]
@@ -31,9 +132,144 @@ pub struct PointsPurchased {
     pub price_lamports: u64,
 }
 
+#[event]
+pub struct CatalogItemUpdated {
+    pub item: Pubkey,
+    pub points_cost: u64,
+    pub inventory: u32,
+    pub active: bool,
+}
+
+#[event]
+pub struct ItemRedeemed {
+    pub item: Pubkey,
+    pub holder: Pubkey,
+    pub voucher_id: u64,
+    pub points_burned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RegionCreated {
+    pub region: Pubkey,
+    pub key: String,
+    pub max_plots: u32,
+    pub base_reward_points: u64,
+}
+
+#[event]
+pub struct PlotDefined {
+    pub plot: Pubkey,
+    pub region: Pubkey,
+    pub x: i32,
+    pub y: i32,
+}
+
 #[event]
 pub struct PlotClaimed {
     pub plot: Pubkey,
     pub owner: Pubkey,
     pub region: String,
+    pub fee_lamports: u64,
+    pub fee_points: u64,
+}
+
+#[event]
+pub struct PlotUpgraded {
+    pub plot: Pubkey,
+    pub owner: Pubkey,
+    pub from_level: u8,
+    pub to_level: u8,
+    pub price_lamports: u64,
+}
+
+#[event]
+pub struct PlotBoostPurchased {
+    pub plot: Pubkey,
+    pub kind: BoostKind,
+    pub magnitude_bps: u16,
+    pub expires_at: i64,
+    pub price_lamports: u64,
+}
+
+#[event]
+pub struct VirtualSessionRecorded {
+    pub plot: Pubkey,
+    pub owner: Pubkey,
+    pub session_id: [u8; 32],
+    pub energy_wh: u64,
+    pub upgrade_level: u8,
+    pub boost_bps: u64,
+    pub session_points: u64,
+    pub owner_points: u64,
+    pub owner_points_deferred: u64,
+}
+
+#[event]
+pub struct PlotRewardsClaimed {
+    pub plot: Pubkey,
+    pub owner: Pubkey,
+    pub elapsed_seconds: i64,
+    pub points: u64,
+    pub points_deferred: u64,
+}
+
+#[event]
+pub struct DelegateGranted {
+    pub driver: Pubkey,
+    pub delegate: Pubkey,
+    pub scopes: u8,
+    pub max_spend_microunits: u64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct DelegateRevoked {
+    pub driver: Pubkey,
+    pub delegate: Pubkey,
+}
+
+#[event]
+pub struct PointsStaked {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub total_staked: u64,
+    pub unlock_at: i64,
+}
+
+#[event]
+pub struct PointsUnstaked {
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub param: GovernanceParam,
+    pub value: u64,
+    pub voting_ends_at: i64,
+}
+
+#[event]
+pub struct VoteCast {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub support: bool,
+    pub power: u64,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub proposal: Pubkey,
+    pub param: GovernanceParam,
+    pub value: u64,
+}
+
+#[event]
+pub struct RecsClaimed {
+    pub operator: Pubkey,
+    pub certificates: u64,
+    pub remaining_wh: u64,
 }
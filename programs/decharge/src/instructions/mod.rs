@@ -1,29 +1,270 @@
 #![allow(unexpected_cfgs)]
 
 use anchor_lang::prelude::*;
-use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_spl::associated_token::{self, AssociatedToken};
+use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_interface::spl_token_2022::{
+    self,
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    instruction::AuthorityType,
+};
+use anchor_spl::token_interface::spl_token_metadata_interface::state::{Field, TokenMetadata};
+use anchor_spl::token_interface::{
+    self, Burn, InitializeMint2, Mint, MintTo, NonTransferableMintInitialize, SetAuthority,
+    Token2022, TokenAccount, TokenInterface, TokenMetadataInitialize, TokenMetadataUpdateField,
+    TransferChecked,
+};
 
 use crate::constants::*;
 use crate::errors::DechargeError;
-use crate::events::{PlotClaimed, PointsPurchased, SessionClosed, SessionStarted, StationRegistered};
+use crate::events::{
+    CatalogItemUpdated, DelegateGranted, DelegateRevoked, EmissionBudgetWarning, ItemRedeemed,
+    LoyaltyTierChanged, PlotBoostPurchased, PlotClaimed, PlotDefined, PlotRewardsClaimed,
+    PlotUpgraded, PointMetadataUpdated, PointsExpired, PointsPurchased, PointsStaked,
+    PointsTransferred, PointsUnstaked, PointsWithdrawn, ProposalCreated, ProposalExecuted,
+    ReceiptIssued, RecsClaimed, ReferralRewarded, RegionCreated, SessionClosed, SessionStarted,
+    SessionStopRequested, StationRegistered, TargetSocReached, TelemetryBatchRecorded,
+    VehicleRegistered, VirtualSessionRecorded, VoteCast,
+};
 use crate::state::*;
-use anchor_lang::solana_program::{program::invoke, system_instruction};
+use anchor_lang::solana_program::{program::invoke, pubkey::MAX_SEED_LEN, system_instruction};
 
 pub fn initialize_platform(
     ctx: Context<InitializePlatform>,
     args: InitializePlatformArgs,
+) -> Result<()> {
+    write_platform_config(
+        &mut ctx.accounts.platform_config,
+        ctx.accounts.admin.key(),
+        ctx.accounts.point_mint.key(),
+        ctx.accounts.payment_treasury.key(),
+        ctx.accounts.world_treasury.key(),
+        ctx.bumps.platform_config,
+        args,
+    )?;
+    ctx.accounts.point_emissions.bump = ctx.bumps.point_emissions;
+
+    Ok(())
+}
+
+/// Same as `initialize_platform`, but creates the point mint under Token-2022 with
+/// metadata stored on the mint itself.
+pub fn initialize_platform_with_metadata(
+    ctx: Context<InitializePlatformWithMetadata>,
+    args: InitializePlatformArgs,
+    metadata: PointMetadataArgs,
+) -> Result<()> {
+    metadata.validate()?;
+    write_platform_config(
+        &mut ctx.accounts.platform_config,
+        ctx.accounts.admin.key(),
+        ctx.accounts.point_mint.key(),
+        ctx.accounts.payment_treasury.key(),
+        ctx.accounts.world_treasury.key(),
+        ctx.bumps.platform_config,
+        args,
+    )?;
+    ctx.accounts.point_emissions.bump = ctx.bumps.point_emissions;
+
+    let update_authority = ctx.accounts.platform_config.key();
+    let token_metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(update_authority))?,
+        mint: ctx.accounts.point_mint.key(),
+        name: metadata.name.clone(),
+        symbol: metadata.symbol.clone(),
+        uri: metadata.uri.clone(),
+        additional_metadata: vec![],
+    };
+    let mint_info = ctx.accounts.point_mint.to_account_info();
+    let required_len = mint_info
+        .data_len()
+        .checked_add(token_metadata.tlv_size_of()?)
+        .ok_or(DechargeError::NumericalOverflow)?;
+    fund_rent_exemption(
+        &ctx.accounts.admin,
+        &mint_info,
+        &ctx.accounts.system_program,
+        required_len,
+    )?;
+
+    let config_seeds: &[&[u8]] = &[PLATFORM_SEED, &[ctx.accounts.platform_config.bump]];
+    let signer_seeds = &[config_seeds];
+    token_interface::token_metadata_initialize(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataInitialize {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                metadata: mint_info.clone(),
+                update_authority: ctx.accounts.platform_config.to_account_info(),
+                mint_authority: ctx.accounts.platform_config.to_account_info(),
+                mint: mint_info,
+            },
+            signer_seeds,
+        ),
+        metadata.name.clone(),
+        metadata.symbol.clone(),
+        metadata.uri.clone(),
+    )?;
+
+    emit!(PointMetadataUpdated {
+        point_mint: ctx.accounts.point_mint.key(),
+        name: metadata.name,
+        symbol: metadata.symbol,
+        uri: metadata.uri,
+    });
+
+    Ok(())
+}
+
+pub fn update_point_metadata(
+    ctx: Context<UpdatePointMetadata>,
+    metadata: PointMetadataArgs,
+) -> Result<()> {
+    metadata.validate()?;
+
+    let mint_info = ctx.accounts.point_mint.to_account_info();
+    let required_len = {
+        let data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+        let mut token_metadata = mint.get_variable_len_extension::<TokenMetadata>()?;
+        let current_size = token_metadata.tlv_size_of()?;
+        token_metadata.update(Field::Name, metadata.name.clone());
+        token_metadata.update(Field::Symbol, metadata.symbol.clone());
+        token_metadata.update(Field::Uri, metadata.uri.clone());
+        (data.len() - current_size)
+            .checked_add(token_metadata.tlv_size_of()?)
+            .ok_or(DechargeError::NumericalOverflow)?
+    };
+    fund_rent_exemption(
+        &ctx.accounts.admin,
+        &mint_info,
+        &ctx.accounts.system_program,
+        required_len,
+    )?;
+
+    let config_seeds: &[&[u8]] = &[PLATFORM_SEED, &[ctx.accounts.platform_config.bump]];
+    let signer_seeds = &[config_seeds];
+    for (field, value) in [
+        (Field::Name, &metadata.name),
+        (Field::Symbol, &metadata.symbol),
+        (Field::Uri, &metadata.uri),
+    ] {
+        token_interface::token_metadata_update_field(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataUpdateField {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: mint_info.clone(),
+                    update_authority: ctx.accounts.platform_config.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            field,
+            value.clone(),
+        )?;
+    }
+
+    emit!(PointMetadataUpdated {
+        point_mint: ctx.accounts.point_mint.key(),
+        name: metadata.name,
+        symbol: metadata.symbol,
+        uri: metadata.uri,
+    });
+
+    Ok(())
+}
+
+pub fn initialize_rec_mint(ctx: Context<InitializeRecMint>) -> Result<()> {
+    ctx.accounts.platform_config.rec_mint = ctx.accounts.rec_mint.key();
+
+    Ok(())
+}
+
+pub fn update_platform_config(
+    ctx: Context<UpdatePlatformConfig>,
+    args: UpdatePlatformConfigArgs,
 ) -> Result<()> {
     let config = &mut ctx.accounts.platform_config;
-    config.admin = ctx.accounts.admin.key();
-    config.oracle = args.oracle;
-    config.point_mint = ctx.accounts.point_mint.key();
-    config.bump = ctx.bumps.platform_config;
-    config.point_rate_microunits = args.point_rate_microunits;
-    config.payment_treasury = ctx.accounts.payment_treasury.key();
-    config.world_treasury = ctx.accounts.world_treasury.key();
+    if let Some(oracle) = args.oracle {
+        config.oracle = oracle;
+    }
+    if let Some(point_rate_microunits) = args.point_rate_microunits {
+        config.point_rate_microunits = point_rate_microunits;
+    }
+    if let Some(ice_baseline_g_per_kwh) = args.ice_baseline_g_per_kwh {
+        config.ice_baseline_g_per_kwh = ice_baseline_g_per_kwh;
+    }
+    if let Some(points_epoch_seconds) = args.points_epoch_seconds {
+        // Stored bucket and emission epochs are counted in the original epoch length.
+        require!(
+            config.points_epoch_seconds == 0
+                || points_epoch_seconds == config.points_epoch_seconds,
+            DechargeError::PointsEpochLocked
+        );
+        if config.points_epoch_seconds == 0 && points_epoch_seconds > 0 {
+            config.points_epoch_start = Clock::get()?.unix_timestamp;
+        }
+        config.points_epoch_seconds = points_epoch_seconds;
+    }
+    if let Some(points_expiry_epochs) = args.points_expiry_epochs {
+        require!(
+            points_expiry_epochs as usize <= MAX_POINT_BUCKETS,
+            DechargeError::InvalidExpiryHorizon
+        );
+        config.points_expiry_epochs = points_expiry_epochs;
+    }
+    if let Some(loyalty_tiers) = args.loyalty_tiers {
+        config.loyalty_tiers = loyalty_tiers;
+    }
+    if let Some(tier_window_seconds) = args.tier_window_seconds {
+        config.tier_window_seconds = tier_window_seconds;
+    }
+    if let Some(referral_threshold_wh) = args.referral_threshold_wh {
+        config.referral_threshold_wh = referral_threshold_wh;
+    }
+    if let Some(referrer_bonus_points) = args.referrer_bonus_points {
+        config.referrer_bonus_points = referrer_bonus_points;
+    }
+    if let Some(referee_bonus_points) = args.referee_bonus_points {
+        config.referee_bonus_points = referee_bonus_points;
+    }
+    if let Some(max_referrals_per_referrer) = args.max_referrals_per_referrer {
+        config.max_referrals_per_referrer = max_referrals_per_referrer;
+    }
+    if let Some(epoch_emission_budget) = args.epoch_emission_budget {
+        config.epoch_emission_budget = epoch_emission_budget;
+    }
+    if let Some(lifetime_emission_budget) = args.lifetime_emission_budget {
+        config.lifetime_emission_budget = lifetime_emission_budget;
+    }
+    if let Some(emission_warning_bps) = args.emission_warning_bps {
+        require!(
+            u64::from(emission_warning_bps) <= BPS_DENOMINATOR,
+            DechargeError::InvalidBasisPoints
+        );
+        config.emission_warning_bps = emission_warning_bps;
+    }
+    if let Some(daily_transfer_limit) = args.daily_transfer_limit {
+        config.daily_transfer_limit = daily_transfer_limit;
+    }
+    if let Some(governance_quorum_power) = args.governance_quorum_power {
+        config.governance_quorum_power = governance_quorum_power;
+    }
+    if let Some(governance_voting_seconds) = args.governance_voting_seconds {
+        config.governance_voting_seconds = governance_voting_seconds;
+    }
+    if let Some(plot_claim_fee_lamports) = args.plot_claim_fee_lamports {
+        config.plot_claim_fee_lamports = plot_claim_fee_lamports;
+    }
+    if let Some(plot_claim_fee_points) = args.plot_claim_fee_points {
+        config.plot_claim_fee_points = plot_claim_fee_points;
+    }
 
-    ctx.accounts.session_counter.next_session = 0;
+    require!(
+        config.epoch_emission_budget == 0 || config.points_epoch_seconds > 0,
+        DechargeError::EmissionEpochRequired
+    );
 
     Ok(())
 }
@@ -35,6 +276,10 @@ pub fn register_station(
     require!(args.name.len() <= MAX_NAME_LEN, DechargeError::DataTooLong);
     require!(args.city.len() <= MAX_CITY_LEN, DechargeError::DataTooLong);
     require!(args.uri.len() <= MAX_URI_LEN, DechargeError::DataTooLong);
+    require!(
+        u64::from(args.renewable_fraction_bps) <= BPS_DENOMINATOR,
+        DechargeError::InvalidBasisPoints
+    );
 
     let station = &mut ctx.accounts.station;
     station.platform = ctx.accounts.platform_config.key();
@@ -49,6 +294,12 @@ pub fn register_station(
     station.pricing_time_microunits = args.pricing_time_microunits;
     station.uri = fit_to_array::<MAX_URI_LEN>(&args.uri);
     station.bump = ctx.bumps.station;
+    station.next_session = 0;
+    station.grid_carbon_g_per_kwh = args.grid_carbon_g_per_kwh;
+    station.renewable_fraction_bps = args.renewable_fraction_bps;
+    station.total_energy_wh = 0;
+    station.total_co2_avoided_g = 0;
+    station.connector_types = args.connector_types;
 
     emit!(StationRegistered {
         station: station.key(),
@@ -59,19 +310,97 @@ pub fn register_station(
     Ok(())
 }
 
+pub fn update_station_carbon_profile(
+    ctx: Context<UpdateStationCarbonProfile>,
+    args: StationCarbonProfileArgs,
+) -> Result<()> {
+    require!(
+        u64::from(args.renewable_fraction_bps) <= BPS_DENOMINATOR,
+        DechargeError::InvalidBasisPoints
+    );
+
+    let station = &mut ctx.accounts.station;
+    station.grid_carbon_g_per_kwh = args.grid_carbon_g_per_kwh;
+    station.renewable_fraction_bps = args.renewable_fraction_bps;
+
+    Ok(())
+}
+
+pub fn register_vehicle(
+    ctx: Context<RegisterVehicle>,
+    args: RegisterVehicleArgs,
+) -> Result<()> {
+    require!(args.battery_capacity_wh > 0, DechargeError::InvalidTelemetry);
+    require!(
+        args.max_ac_kw > 0 || args.max_dc_kw > 0,
+        DechargeError::InvalidTelemetry
+    );
+
+    let vehicle = &mut ctx.accounts.vehicle;
+    vehicle.owner = ctx.accounts.driver.key();
+    vehicle.vin_hash = args.vin_hash;
+    vehicle.battery_capacity_wh = args.battery_capacity_wh;
+    vehicle.max_ac_kw = args.max_ac_kw;
+    vehicle.max_dc_kw = args.max_dc_kw;
+    vehicle.connector_types = args.connector_types;
+    vehicle.bump = ctx.bumps.vehicle;
+
+    emit!(VehicleRegistered {
+        vehicle: vehicle.key(),
+        owner: vehicle.owner,
+        battery_capacity_wh: vehicle.battery_capacity_wh,
+        connector_types: vehicle.connector_types,
+    });
+
+    Ok(())
+}
+
 pub fn start_session(
     ctx: Context<StartSession>,
     args: StartSessionArgs,
 ) -> Result<()> {
     require!(ctx.accounts.station.active, DechargeError::StationInactive);
+    require!(
+        !matches!(args.target_soc_percent, Some(target) if target > 100),
+        DechargeError::InvalidTelemetry
+    );
+
+    if let Some(vehicle) = &ctx.accounts.vehicle {
+        require!(
+            ctx.accounts.station.supports_connectors(vehicle.connector_types),
+            DechargeError::ConnectorMismatch
+        );
+    }
 
-    let session_index = ctx.accounts.session_counter.next_session;
+    let session_index = ctx.accounts.station.next_session;
     let driver_key = ctx.accounts.driver.key();
 
+    let delegate_key = match &ctx.accounts.driver_delegate {
+        Some(delegate) => {
+            delegate.authorize(DELEGATE_SCOPE_START_SESSION, Clock::get()?.unix_timestamp)?;
+            require!(
+                delegate.spent_microunits < delegate.max_spend_microunits,
+                DechargeError::DelegateSpendExceeded
+            );
+            delegate.key()
+        }
+        None => {
+            require!(
+                ctx.accounts.driver_authority.key() == driver_key,
+                DechargeError::Unauthorized
+            );
+            Pubkey::default()
+        }
+    };
+
     let driver_profile = &mut ctx.accounts.driver_profile;
     if driver_profile.driver == Pubkey::default() {
         driver_profile.driver = driver_key;
         driver_profile.bump = ctx.bumps.driver_profile;
+        if let Some(referrer_profile) = &ctx.accounts.referrer_profile {
+            require!(referrer_profile.driver != driver_key, DechargeError::SelfReferral);
+            driver_profile.referrer = referrer_profile.driver;
+        }
     } else {
         require!(driver_profile.driver == driver_key, DechargeError::Unauthorized);
     }
@@ -89,8 +418,23 @@ pub fn start_session(
     session.opened_at = args.timestamp;
     session.closed_at = 0;
     session.bump = ctx.bumps.session;
+    session.delegate = delegate_key;
+    session.stop_requested = false;
+    session.vehicle = ctx
+        .accounts
+        .vehicle
+        .as_ref()
+        .map(|vehicle| vehicle.key())
+        .unwrap_or_default();
+    session.start_soc_percent = None;
+    session.end_soc_percent = None;
+    session.target_soc_percent = args.target_soc_percent;
+    session.auto_close_at_target = args.auto_close_at_target;
+    if let Some(soc_percent) = args.start_soc_percent {
+        session.record_soc(soc_percent)?;
+    }
 
-    ctx.accounts.session_counter.next_session = session_index
+    ctx.accounts.station.next_session = session_index
         .checked_add(1)
         .ok_or(DechargeError::NumericalOverflow)?;
 
@@ -104,12 +448,38 @@ pub fn start_session(
     Ok(())
 }
 
+pub fn request_stop_session(ctx: Context<RequestStopSession>) -> Result<()> {
+    let session = &mut ctx.accounts.session;
+    require!(session.status == SessionStatus::Active, DechargeError::SessionClosed);
+
+    let now = Clock::get()?.unix_timestamp;
+    match &ctx.accounts.driver_delegate {
+        Some(delegate) => delegate.authorize(DELEGATE_SCOPE_STOP_SESSION, now)?,
+        None => require!(
+            ctx.accounts.driver_authority.key() == session.driver,
+            DechargeError::Unauthorized
+        ),
+    }
+
+    session.stop_requested = true;
+
+    emit!(SessionStopRequested {
+        session: session.key(),
+        driver: session.driver,
+        authority: ctx.accounts.driver_authority.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
 pub fn record_telemetry(
     ctx: Context<RecordTelemetry>,
     args: TelemetryArgs,
 ) -> Result<()> {
     let session = &mut ctx.accounts.session;
     require!(session.status == SessionStatus::Active, DechargeError::SessionClosed);
+    require!(!session.stop_requested, DechargeError::SessionStopping);
 
     session.energy_wh = session
         .energy_wh
@@ -121,6 +491,99 @@ pub fn record_telemetry(
         .ok_or(DechargeError::NumericalOverflow)?;
     session.telemetry_hash = args.telemetry_hash;
 
+    if let Some(soc_percent) = args.soc_percent {
+        track_soc(session, soc_percent)?;
+    }
+
+    if let Some(vehicle) = session_vehicle(session, ctx.accounts.vehicle.as_ref())? {
+        vehicle.validate_delivery(session.energy_wh, args.energy_delta_wh, args.seconds_delta)?;
+    }
+
+    Ok(())
+}
+
+pub fn initialize_telemetry_log(ctx: Context<InitializeTelemetryLog>) -> Result<()> {
+    require!(
+        ctx.accounts.session.status == SessionStatus::Active,
+        DechargeError::SessionClosed
+    );
+
+    let mut log = ctx.accounts.telemetry_log.load_init()?;
+    log.session = ctx.accounts.session.key();
+    log.head = 0;
+    log.count = 0;
+    log.bump = ctx.bumps.telemetry_log;
+
+    Ok(())
+}
+
+pub fn record_telemetry_batch(
+    ctx: Context<RecordTelemetryBatch>,
+    args: TelemetryBatchArgs,
+) -> Result<()> {
+    let session = &mut ctx.accounts.session;
+    require!(session.status == SessionStatus::Active, DechargeError::SessionClosed);
+    require!(!session.stop_requested, DechargeError::SessionStopping);
+    require!(
+        !args.samples.is_empty() && args.samples.len() <= TELEMETRY_LOG_CAPACITY,
+        DechargeError::InvalidTelemetry
+    );
+
+    let vehicle = session_vehicle(session, ctx.accounts.vehicle.as_ref())?;
+    let mut log = ctx.accounts.telemetry_log.load_mut()?;
+    // Single-sample `record_telemetry` calls may have advanced the session past the log.
+    let session_timestamp = i64::try_from(session.seconds_elapsed)
+        .ok()
+        .and_then(|elapsed| session.opened_at.checked_add(elapsed))
+        .ok_or(DechargeError::NumericalOverflow)?;
+    let (mut last_timestamp, mut last_wh) = match log.latest() {
+        Some(sample) => (
+            sample.timestamp.max(session_timestamp),
+            sample.cumulative_wh.max(session.energy_wh),
+        ),
+        None => (session_timestamp, session.energy_wh),
+    };
+
+    let mut recorded = 0u32;
+    for sample in &args.samples {
+        require!(sample.timestamp >= last_timestamp, DechargeError::InvalidTelemetry);
+        require!(sample.cumulative_wh >= last_wh, DechargeError::InvalidTelemetry);
+        track_soc(session, sample.soc_percent)?;
+        if let Some(vehicle) = vehicle {
+            vehicle.validate_telemetry(sample.cumulative_wh, u64::from(sample.power_watts))?;
+        }
+
+        log.push(TelemetrySample {
+            timestamp: sample.timestamp,
+            cumulative_wh: sample.cumulative_wh,
+            power_watts: sample.power_watts,
+            soc_percent: sample.soc_percent,
+            _padding: [0; 3],
+        });
+        last_timestamp = sample.timestamp;
+        last_wh = sample.cumulative_wh;
+        recorded += 1;
+        // Samples after the target state of charge belong to a stopped session.
+        if session.stop_requested {
+            break;
+        }
+    }
+
+    session.energy_wh = last_wh;
+    session.seconds_elapsed = last_timestamp
+        .checked_sub(session.opened_at)
+        .and_then(|elapsed| u64::try_from(elapsed).ok())
+        .ok_or(DechargeError::InvalidTelemetry)?
+        .max(session.seconds_elapsed);
+    session.telemetry_hash = args.telemetry_hash;
+
+    emit!(TelemetryBatchRecorded {
+        session: session.key(),
+        samples: recorded,
+        energy_wh: session.energy_wh,
+        last_timestamp,
+    });
+
     Ok(())
 }
 
@@ -134,18 +597,32 @@ pub fn close_session(
     let station = &ctx.accounts.station;
     let config = &ctx.accounts.platform_config;
 
-    session.energy_wh = args.final_energy_wh;
-    session.seconds_elapsed = args.final_seconds;
+    // Once a stop is requested, nothing delivered after the last recorded telemetry is billed.
+    let (final_energy_wh, final_seconds) = if session.stop_requested {
+        (
+            args.final_energy_wh.min(session.energy_wh),
+            args.final_seconds.min(session.seconds_elapsed),
+        )
+    } else {
+        (args.final_energy_wh, args.final_seconds)
+    };
+
+    session.energy_wh = final_energy_wh;
+    session.seconds_elapsed = final_seconds;
     session.telemetry_hash = args.telemetry_hash;
     session.closed_at = args.closed_at;
     session.status = SessionStatus::Closed;
+    if let Some(soc_percent) = args.end_soc_percent {
+        session.record_soc(soc_percent)?;
+    }
+    if let Some(vehicle) = session_vehicle(session, ctx.accounts.vehicle.as_deref())? {
+        vehicle.validate_delivery(final_energy_wh, final_energy_wh, final_seconds)?;
+    }
 
-    let energy_component = args
-        .final_energy_wh
+    let energy_component = final_energy_wh
         .checked_mul(station.pricing_energy_microunits)
         .ok_or(DechargeError::NumericalOverflow)?;
-    let time_component = args
-        .final_seconds
+    let time_component = final_seconds
         .checked_mul(station.pricing_time_microunits)
         .ok_or(DechargeError::NumericalOverflow)?;
     let total_price = energy_component
@@ -153,31 +630,101 @@ pub fn close_session(
         .ok_or(DechargeError::NumericalOverflow)?;
     session.price_microunits = total_price;
 
-    let points_micros = args
-        .final_energy_wh
-        .checked_mul(config.point_rate_microunits)
-        .ok_or(DechargeError::NumericalOverflow)?;
-    let points_to_mint = points_micros
-        .checked_div(MICROS_PER_POINT)
+    // The spend cap is enforced when a delegated session starts; closing always records
+    // the full price, and a delegate revoked mid-session has nothing left to charge.
+    if session.delegate != Pubkey::default() {
+        let delegate_info = ctx
+            .accounts
+            .driver_delegate
+            .as_ref()
+            .ok_or(DechargeError::Unauthorized)?;
+        if !delegate_info.data_is_empty() {
+            require_keys_eq!(*delegate_info.owner, crate::ID, DechargeError::Unauthorized);
+            let mut data = delegate_info.try_borrow_mut_data()?;
+            let mut delegate = DriverDelegate::try_deserialize(&mut &data[..])?;
+            delegate.spent_microunits = delegate.spent_microunits.saturating_add(total_price);
+            delegate.try_serialize(&mut &mut data[..])?;
+        }
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let driver_profile = &mut ctx.accounts.driver_profile;
+    driver_profile.record_tier_activity(config.tier_window(now), final_energy_wh)?;
+    let previous_tier = driver_profile.tier;
+    driver_profile.tier = if config.tier_window_seconds > 0 {
+        config.loyalty_tier(
+            driver_profile.trailing_energy_wh(),
+            driver_profile.trailing_sessions(),
+        )
+    } else {
+        config.loyalty_tier(
+            driver_profile.total_energy_wh.saturating_add(final_energy_wh),
+            driver_profile.total_sessions.saturating_add(1),
+        )
+    };
+    if driver_profile.tier != previous_tier {
+        emit!(LoyaltyTierChanged {
+            driver: driver_profile.driver,
+            previous_tier,
+            tier: driver_profile.tier,
+        });
+    }
+
+    let scaled_points = u128::from(final_energy_wh)
+        * u128::from(config.point_rate_microunits)
+        * u128::from(config.tier_multiplier_bps(driver_profile.tier));
+    let points_owed = driver_profile
+        .carry_points(scaled_points)?
+        .checked_add(driver_profile.deferred_points)
         .ok_or(DechargeError::NumericalOverflow)?;
+
+    let mut emissions =
+        budgeted_emissions(ctx.accounts.point_emissions.as_deref_mut(), config, now)?;
+    let was_near_cap = emissions.as_deref().is_some_and(|emissions| emissions.near_cap(config));
+    let points_to_mint = allocate_emissions(emissions.as_deref_mut(), config, points_owed)?;
+    driver_profile.deferred_points = points_owed - points_to_mint;
     session.points_earned = points_to_mint;
 
-    let driver_profile = &mut ctx.accounts.driver_profile;
+    let co2_avoided_g =
+        station.co2_avoided_g(final_energy_wh, config.ice_baseline_g_per_kwh)?;
+
     driver_profile.total_sessions = driver_profile
         .total_sessions
         .checked_add(1)
         .ok_or(DechargeError::NumericalOverflow)?;
     driver_profile.total_energy_wh = driver_profile
         .total_energy_wh
-        .checked_add(args.final_energy_wh)
+        .checked_add(final_energy_wh)
         .ok_or(DechargeError::NumericalOverflow)?;
     driver_profile.total_points_earned = driver_profile
         .total_points_earned
         .checked_add(points_to_mint)
         .ok_or(DechargeError::NumericalOverflow)?;
-    driver_profile.outstanding_points = driver_profile
-        .outstanding_points
-        .checked_add(points_to_mint)
+    driver_profile.credit_points(config.points_epoch(now), points_to_mint)?;
+    driver_profile.total_co2_avoided_g = driver_profile
+        .total_co2_avoided_g
+        .checked_add(co2_avoided_g)
+        .ok_or(DechargeError::NumericalOverflow)?;
+
+    let station = &mut ctx.accounts.station;
+    station.total_energy_wh = station
+        .total_energy_wh
+        .checked_add(final_energy_wh)
+        .ok_or(DechargeError::NumericalOverflow)?;
+    station.total_co2_avoided_g = station
+        .total_co2_avoided_g
+        .checked_add(co2_avoided_g)
+        .ok_or(DechargeError::NumericalOverflow)?;
+
+    let renewable_wh = station.renewable_wh(final_energy_wh)?;
+    let operator_recs = &mut ctx.accounts.operator_recs;
+    if operator_recs.operator == Pubkey::default() {
+        operator_recs.operator = station.operator;
+        operator_recs.bump = ctx.bumps.operator_recs;
+    }
+    operator_recs.accrued_renewable_wh = operator_recs
+        .accrued_renewable_wh
+        .checked_add(renewable_wh)
         .ok_or(DechargeError::NumericalOverflow)?;
 
     let points_vault = &mut ctx.accounts.points_vault;
@@ -196,56 +743,219 @@ pub fn close_session(
         );
     }
 
-    let mint_seeds: &[&[u8]] = &[PLATFORM_SEED, &[config.bump]];
+    mint_platform_points(
+        &ctx.accounts.token_program,
+        &ctx.accounts.point_mint,
+        &ctx.accounts.vault_token_account,
+        &ctx.accounts.platform_config,
+        points_to_mint,
+    )?;
+
+    let referrer_bonus = match ctx.accounts.referrer_profile.as_ref() {
+        Some(referrer_profile)
+            if config.max_referrals_per_referrer == 0
+                || referrer_profile.referrals_rewarded < config.max_referrals_per_referrer =>
+        {
+            config.referrer_bonus_points
+        }
+        _ => 0,
+    };
+    let referee_bonus = config.referee_bonus_points;
+    let referral_budget = referrer_bonus
+        .checked_add(referee_bonus)
+        .ok_or(DechargeError::NumericalOverflow)?;
+    if ctx.accounts.driver_profile.referral_due(config.referral_threshold_wh)
+        && emissions.as_deref().map_or(u64::MAX, |emissions| emissions.remaining(config))
+            >= referral_budget
+    {
+        if let (Some(referrer_profile), Some(referrer_vault), Some(referrer_token_account)) = (
+            ctx.accounts.referrer_profile.as_mut(),
+            ctx.accounts.referrer_points_vault.as_ref(),
+            ctx.accounts.referrer_vault_token_account.as_ref(),
+        ) {
+            require_keys_eq!(
+                referrer_vault.token_account,
+                referrer_token_account.key(),
+                DechargeError::PointsVaultMismatch
+            );
+
+            let driver_profile = &mut ctx.accounts.driver_profile;
+            let points_epoch = config.points_epoch(now);
+            allocate_emissions(emissions.as_deref_mut(), config, referral_budget)?;
+
+            driver_profile.referral_rewarded = true;
+            driver_profile.total_points_earned = driver_profile
+                .total_points_earned
+                .checked_add(referee_bonus)
+                .ok_or(DechargeError::NumericalOverflow)?;
+            driver_profile.credit_points(points_epoch, referee_bonus)?;
+
+            if referrer_bonus > 0 {
+                referrer_profile.referrals_rewarded = referrer_profile
+                    .referrals_rewarded
+                    .checked_add(1)
+                    .ok_or(DechargeError::NumericalOverflow)?;
+                referrer_profile.total_points_earned = referrer_profile
+                    .total_points_earned
+                    .checked_add(referrer_bonus)
+                    .ok_or(DechargeError::NumericalOverflow)?;
+                referrer_profile.credit_points(points_epoch, referrer_bonus)?;
+            }
+
+            mint_platform_points(
+                &ctx.accounts.token_program,
+                &ctx.accounts.point_mint,
+                &ctx.accounts.vault_token_account,
+                &ctx.accounts.platform_config,
+                referee_bonus,
+            )?;
+            mint_platform_points(
+                &ctx.accounts.token_program,
+                &ctx.accounts.point_mint,
+                referrer_token_account,
+                &ctx.accounts.platform_config,
+                referrer_bonus,
+            )?;
+
+            emit!(ReferralRewarded {
+                referrer: referrer_profile.driver,
+                referee: driver_profile.driver,
+                referrer_bonus,
+                referee_bonus,
+            });
+        }
+    }
+
+    warn_emission_budget(emissions.as_deref(), config, was_near_cap);
+
+    emit!(SessionClosed {
+        session: session.key(),
+        energy_wh: final_energy_wh,
+        seconds: final_seconds,
+        points_minted: points_to_mint,
+        start_soc_percent: session.start_soc_percent,
+        end_soc_percent: session.end_soc_percent,
+        co2_avoided_g,
+        tier: ctx.accounts.driver_profile.tier,
+        points_deferred: ctx.accounts.driver_profile.deferred_points,
+    });
+
+    if ctx.accounts.session_receipt.is_some() {
+        mint_receipt_token(ctx.accounts, ctx.bumps.receipt_mint.unwrap_or_default())?;
+    }
+    let session = &ctx.accounts.session;
+    if let Some(receipt) = ctx.accounts.session_receipt.as_mut() {
+        receipt.session = session.key();
+        receipt.mint = ctx
+            .accounts
+            .receipt_mint
+            .as_ref()
+            .map(|mint| mint.key())
+            .unwrap_or_default();
+        receipt.driver = session.driver;
+        receipt.station = session.station;
+        receipt.energy_wh = session.energy_wh;
+        receipt.price_microunits = session.price_microunits;
+        receipt.points_earned = session.points_earned;
+        receipt.telemetry_hash = session.telemetry_hash;
+        receipt.closed_at = session.closed_at;
+        receipt.bump = ctx.bumps.session_receipt.unwrap_or_default();
+
+        emit!(ReceiptIssued {
+            receipt: receipt.key(),
+            mint: receipt.mint,
+            session: receipt.session,
+            driver: receipt.driver,
+            station: receipt.station,
+            energy_wh: receipt.energy_wh,
+            price_microunits: receipt.price_microunits,
+            telemetry_hash: receipt.telemetry_hash,
+        });
+    }
+
+    Ok(())
+}
+
+pub fn claim_recs(ctx: Context<ClaimRecs>) -> Result<()> {
+    let operator_recs = &mut ctx.accounts.operator_recs;
+    let certificates = operator_recs.accrued_renewable_wh / WH_PER_MWH;
+    require!(certificates > 0, DechargeError::NothingToClaim);
+
+    operator_recs.accrued_renewable_wh %= WH_PER_MWH;
+    operator_recs.total_recs_claimed = operator_recs
+        .total_recs_claimed
+        .checked_add(certificates)
+        .ok_or(DechargeError::NumericalOverflow)?;
+
+    let mint_seeds: &[&[u8]] = &[PLATFORM_SEED, &[ctx.accounts.platform_config.bump]];
     let signer_seeds = &[mint_seeds];
     let mint_ctx = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
         MintTo {
-            mint: ctx.accounts.point_mint.to_account_info(),
-            to: ctx.accounts.vault_token_account.to_account_info(),
+            mint: ctx.accounts.rec_mint.to_account_info(),
+            to: ctx.accounts.operator_token_account.to_account_info(),
             authority: ctx.accounts.platform_config.to_account_info(),
         },
         signer_seeds,
     );
+    token_interface::mint_to(mint_ctx, certificates)?;
 
-    if points_to_mint > 0 {
-        token::mint_to(mint_ctx, points_to_mint)?;
-    }
+    emit!(RecsClaimed {
+        operator: operator_recs.operator,
+        certificates,
+        remaining_wh: operator_recs.accrued_renewable_wh,
+    });
 
-    emit!(SessionClosed {
-        session: session.key(),
-        energy_wh: args.final_energy_wh,
-        seconds: args.final_seconds,
-        points_minted: points_to_mint,
+    Ok(())
+}
+
+pub fn grant_driver_delegate(
+    ctx: Context<GrantDriverDelegate>,
+    args: GrantDriverDelegateArgs,
+) -> Result<()> {
+    require!(
+        args.expires_at > Clock::get()?.unix_timestamp,
+        DechargeError::DelegateExpired
+    );
+
+    let delegate = &mut ctx.accounts.driver_delegate;
+    delegate.driver = ctx.accounts.driver.key();
+    delegate.delegate = args.delegate;
+    delegate.scopes = args.scopes;
+    delegate.max_spend_microunits = args.max_spend_microunits;
+    delegate.spent_microunits = 0;
+    delegate.expires_at = args.expires_at;
+    delegate.bump = ctx.bumps.driver_delegate;
+
+    emit!(DelegateGranted {
+        driver: delegate.driver,
+        delegate: delegate.delegate,
+        scopes: delegate.scopes,
+        max_spend_microunits: delegate.max_spend_microunits,
+        expires_at: delegate.expires_at,
     });
 
     Ok(())
 }
 
-pub fn purchase_points(
-    ctx: Context<PurchasePoints>,
-    args: PurchasePointsArgs,
+pub fn revoke_driver_delegate(ctx: Context<RevokeDriverDelegate>) -> Result<()> {
+    emit!(DelegateRevoked {
+        driver: ctx.accounts.driver.key(),
+        delegate: ctx.accounts.driver_delegate.delegate,
+    });
+
+    Ok(())
+}
+
+pub fn withdraw_points(
+    ctx: Context<WithdrawPoints>,
+    args: WithdrawPointsArgs,
 ) -> Result<()> {
     require!(
         ctx.accounts.driver_profile.outstanding_points >= args.amount,
         DechargeError::InsufficientPoints
     );
 
-    let transfer_ix = system_instruction::transfer(
-        &ctx.accounts.buyer.key(),
-        &ctx.accounts.payment_treasury.key(),
-        args.price_lamports,
-    );
-
-    invoke(
-        &transfer_ix,
-        &[ 
-            ctx.accounts.buyer.to_account_info(),
-            ctx.accounts.payment_treasury.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-        ],
-    )?;
-
     let vault_seeds: &[&[u8]] = &[
         POINTS_VAULT_SEED,
         ctx.accounts.driver_profile.driver.as_ref(),
@@ -255,305 +965,2632 @@ pub fn purchase_points(
 
     let transfer_ctx = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
-        Transfer {
+        TransferChecked {
             from: ctx.accounts.vault_token_account.to_account_info(),
-            to: ctx.accounts.buyer_token_account.to_account_info(),
+            mint: ctx.accounts.point_mint.to_account_info(),
+            to: ctx.accounts.driver_token_account.to_account_info(),
             authority: ctx.accounts.points_vault.to_account_info(),
         },
         signer_seeds,
     );
 
-    token::transfer(transfer_ctx, args.amount)?;
+    token_interface::transfer_checked(transfer_ctx, args.amount, ctx.accounts.point_mint.decimals)?;
 
-    ctx.accounts.driver_profile.outstanding_points = ctx
-        .accounts
-        .driver_profile
-        .outstanding_points
-        .checked_sub(args.amount)
-        .ok_or(DechargeError::NumericalOverflow)?;
+    ctx.accounts.driver_profile.debit_points(args.amount)?;
 
-    emit!(PointsPurchased {
-        buyer: ctx.accounts.buyer.key(),
+    emit!(PointsWithdrawn {
         driver: ctx.accounts.driver_profile.driver,
         amount: args.amount,
-        price_lamports: args.price_lamports,
     });
 
     Ok(())
 }
 
-pub fn claim_world_plot(
-    ctx: Context<ClaimWorldPlot>,
-    region_key: [u8; 64],
+pub fn transfer_points(
+    ctx: Context<TransferPoints>,
+    args: TransferPointsArgs,
 ) -> Result<()> {
-    let plot = &mut ctx.accounts.plot;
-    plot.owner = ctx.accounts.claimant.key();
-    plot.region_key = region_key;
-    plot.slot_capacity = 1;
-    plot.upgrade_level = 1;
-    plot.last_reward_time = Clock::get()?.unix_timestamp;
-    plot.bump = ctx.bumps.plot;
+    let sender_key = ctx.accounts.sender_profile.driver;
+    let recipient_key = ctx.accounts.recipient_profile.driver;
+    require!(sender_key != recipient_key, DechargeError::SelfTransfer);
 
-    emit!(PlotClaimed {
-        plot: plot.key(),
-        owner: plot.owner,
-        region: String::from_utf8_lossy(&region_key).trim_end_matches('\0').to_string(),
-    });
+    let now = Clock::get()?.unix_timestamp;
+    let config = &ctx.accounts.platform_config;
+    let sender_profile = &mut ctx.accounts.sender_profile;
+    sender_profile.record_transfer(
+        u64::try_from(now / SECONDS_PER_DAY).unwrap_or_default(),
+        args.amount,
+        config.daily_transfer_limit,
+    )?;
+    sender_profile.move_points(
+        &mut ctx.accounts.recipient_profile,
+        args.amount,
+        config.points_epoch(now),
+    )?;
 
-    Ok(())
-}
+    let recipient_vault = &mut ctx.accounts.recipient_points_vault;
+    if recipient_vault.driver == Pubkey::default() {
+        recipient_vault.driver = recipient_key;
+        recipient_vault.token_account = ctx.accounts.recipient_vault_token_account.key();
+        recipient_vault.bump = ctx.bumps.recipient_points_vault;
+    } else {
+        require!(
+            recipient_vault.token_account == ctx.accounts.recipient_vault_token_account.key(),
+            DechargeError::PointsVaultMismatch
+        );
+    }
 
-#[derive(Accounts)]
-#[instruction(args: InitializePlatformArgs)]
-pub struct InitializePlatform<'info> {
-    #[account(mut)]
-    pub admin: Signer<'info>,
-    #[account(
+    let vault_seeds: &[&[u8]] = &[
+        POINTS_VAULT_SEED,
+        sender_key.as_ref(),
+        &[ctx.accounts.sender_points_vault.bump],
+    ];
+    let signer_seeds = &[vault_seeds];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.sender_vault_token_account.to_account_info(),
+            mint: ctx.accounts.point_mint.to_account_info(),
+            to: ctx.accounts.recipient_vault_token_account.to_account_info(),
+            authority: ctx.accounts.sender_points_vault.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(transfer_ctx, args.amount, ctx.accounts.point_mint.decimals)?;
+
+    emit!(PointsTransferred {
+        from: sender_key,
+        to: recipient_key,
+        amount: args.amount,
+    });
+
+    Ok(())
+}
+
+pub fn purchase_points(
+    ctx: Context<PurchasePoints>,
+    args: PurchasePointsArgs,
+) -> Result<()> {
+    require!(
+        ctx.accounts.driver_profile.outstanding_points >= args.amount,
+        DechargeError::InsufficientPoints
+    );
+
+    let transfer_ix = system_instruction::transfer(
+        &ctx.accounts.buyer.key(),
+        &ctx.accounts.payment_treasury.key(),
+        args.price_lamports,
+    );
+
+    invoke(
+        &transfer_ix,
+        &[ 
+            ctx.accounts.buyer.to_account_info(),
+            ctx.accounts.payment_treasury.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    let vault_seeds: &[&[u8]] = &[
+        POINTS_VAULT_SEED,
+        ctx.accounts.driver_profile.driver.as_ref(),
+        &[ctx.accounts.points_vault.bump],
+    ];
+    let signer_seeds = &[vault_seeds];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.vault_token_account.to_account_info(),
+            mint: ctx.accounts.point_mint.to_account_info(),
+            to: ctx.accounts.buyer_token_account.to_account_info(),
+            authority: ctx.accounts.points_vault.to_account_info(),
+        },
+        signer_seeds,
+    );
+
+    token_interface::transfer_checked(transfer_ctx, args.amount, ctx.accounts.point_mint.decimals)?;
+
+    ctx.accounts.driver_profile.debit_points(args.amount)?;
+
+    emit!(PointsPurchased {
+        buyer: ctx.accounts.buyer.key(),
+        driver: ctx.accounts.driver_profile.driver,
+        amount: args.amount,
+        price_lamports: args.price_lamports,
+    });
+
+    Ok(())
+}
+
+pub fn stake_points(ctx: Context<StakePoints>, args: StakePointsArgs) -> Result<()> {
+    require!(
+        args.lock_seconds > 0 && args.lock_seconds <= MAX_STAKE_LOCK_SECONDS,
+        DechargeError::InvalidLockDuration
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let unlock_at = now
+        .checked_add(args.lock_seconds)
+        .ok_or(DechargeError::NumericalOverflow)?;
+
+    let stake = &mut ctx.accounts.stake;
+    if stake.owner == Pubkey::default() {
+        stake.owner = ctx.accounts.owner.key();
+        stake.token_account = ctx.accounts.stake_token_account.key();
+        stake.bump = ctx.bumps.stake;
+    }
+    stake.unlock_at = stake.unlock_at.max(unlock_at);
+    stake.amount = stake
+        .amount
+        .checked_add(args.amount)
+        .ok_or(DechargeError::NumericalOverflow)?;
+
+    if args.amount > 0 {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.owner_token_account.to_account_info(),
+                mint: ctx.accounts.point_mint.to_account_info(),
+                to: ctx.accounts.stake_token_account.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        );
+        token_interface::transfer_checked(
+            transfer_ctx,
+            args.amount,
+            ctx.accounts.point_mint.decimals,
+        )?;
+    }
+
+    emit!(PointsStaked {
+        owner: stake.owner,
+        amount: args.amount,
+        total_staked: stake.amount,
+        unlock_at: stake.unlock_at,
+    });
+
+    Ok(())
+}
+
+pub fn unstake_points(ctx: Context<UnstakePoints>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let stake = &ctx.accounts.stake;
+    require!(now >= stake.unlock_at, DechargeError::StakeLocked);
+    require!(stake.amount > 0, DechargeError::NothingToClaim);
+
+    let amount = stake.amount;
+    let stake_seeds: &[&[u8]] = &[POINT_STAKE_SEED, stake.owner.as_ref(), &[stake.bump]];
+    let signer_seeds = &[stake_seeds];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.stake_token_account.to_account_info(),
+            mint: ctx.accounts.point_mint.to_account_info(),
+            to: ctx.accounts.owner_token_account.to_account_info(),
+            authority: ctx.accounts.stake.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(transfer_ctx, amount, ctx.accounts.point_mint.decimals)?;
+
+    ctx.accounts.stake.amount = 0;
+
+    emit!(PointsUnstaked {
+        owner: ctx.accounts.stake.owner,
+        amount,
+    });
+
+    Ok(())
+}
+
+pub fn create_proposal(ctx: Context<CreateProposal>, args: CreateProposalArgs) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.platform_config;
+    require!(config.governance_quorum_power > 0, DechargeError::GovernanceDisabled);
+    require!(
+        ctx.accounts.proposer_stake.voting_power(now) > 0,
+        DechargeError::NoVotingPower
+    );
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.id = config.next_proposal;
+    proposal.param = args.param;
+    proposal.value = args.value;
+    proposal.voting_ends_at = now
+        .checked_add(config.governance_voting_seconds)
+        .ok_or(DechargeError::NumericalOverflow)?;
+    proposal.quorum_power = config.governance_quorum_power;
+    proposal.votes_for = 0;
+    proposal.votes_against = 0;
+    proposal.executed = false;
+    proposal.bump = ctx.bumps.proposal;
+
+    config.next_proposal = config
+        .next_proposal
+        .checked_add(1)
+        .ok_or(DechargeError::NumericalOverflow)?;
+
+    emit!(ProposalCreated {
+        proposal: proposal.key(),
+        proposer: proposal.proposer,
+        param: proposal.param,
+        value: proposal.value,
+        voting_ends_at: proposal.voting_ends_at,
+    });
+
+    Ok(())
+}
+
+pub fn cast_vote(ctx: Context<CastVote>, args: CastVoteArgs) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let proposal = &mut ctx.accounts.proposal;
+    let stake = &ctx.accounts.voter_stake;
+    require!(now < proposal.voting_ends_at, DechargeError::VotingClosed);
+    require!(
+        stake.unlock_at >= proposal.voting_ends_at,
+        DechargeError::StakeUnlocksBeforeVoteEnds
+    );
+
+    let power = stake.voting_power(now);
+    require!(power > 0, DechargeError::NoVotingPower);
+
+    let tally = if args.support {
+        &mut proposal.votes_for
+    } else {
+        &mut proposal.votes_against
+    };
+    *tally = tally.checked_add(power).ok_or(DechargeError::NumericalOverflow)?;
+
+    let vote_record = &mut ctx.accounts.vote_record;
+    vote_record.proposal = proposal.key();
+    vote_record.voter = ctx.accounts.voter.key();
+    vote_record.support = args.support;
+    vote_record.power = power;
+    vote_record.bump = ctx.bumps.vote_record;
+
+    emit!(VoteCast {
+        proposal: proposal.key(),
+        voter: vote_record.voter,
+        support: args.support,
+        power,
+    });
+
+    Ok(())
+}
+
+pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let proposal = &mut ctx.accounts.proposal;
+    require!(now >= proposal.voting_ends_at, DechargeError::VotingOpen);
+    require!(!proposal.executed, DechargeError::ProposalAlreadyExecuted);
+    require!(proposal.passed(), DechargeError::ProposalNotPassed);
+
+    proposal
+        .param
+        .apply(&mut ctx.accounts.platform_config, proposal.value)?;
+    proposal.executed = true;
+
+    emit!(ProposalExecuted {
+        proposal: proposal.key(),
+        param: proposal.param,
+        value: proposal.value,
+    });
+
+    Ok(())
+}
+
+pub fn expire_points(ctx: Context<ExpirePoints>) -> Result<()> {
+    let Some(cutoff_epoch) = ctx
+        .accounts
+        .platform_config
+        .points_expiry_cutoff(Clock::get()?.unix_timestamp)
+    else {
+        return Ok(());
+    };
+
+    let driver_profile = &mut ctx.accounts.driver_profile;
+    let expired = driver_profile
+        .take_expired_points(cutoff_epoch, ctx.accounts.vault_token_account.amount)?;
+    if expired == 0 {
+        return Ok(());
+    }
+
+    let vault_seeds: &[&[u8]] = &[
+        POINTS_VAULT_SEED,
+        driver_profile.driver.as_ref(),
+        &[ctx.accounts.points_vault.bump],
+    ];
+    let signer_seeds = &[vault_seeds];
+    let burn_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Burn {
+            mint: ctx.accounts.point_mint.to_account_info(),
+            from: ctx.accounts.vault_token_account.to_account_info(),
+            authority: ctx.accounts.points_vault.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::burn(burn_ctx, expired)?;
+
+    emit!(PointsExpired {
+        driver: driver_profile.driver,
+        amount: expired,
+        cutoff_epoch,
+    });
+
+    Ok(())
+}
+
+pub fn create_catalog_item(
+    ctx: Context<CreateCatalogItem>,
+    args: CreateCatalogItemArgs,
+) -> Result<()> {
+    require!(args.name.len() <= MAX_NAME_LEN, DechargeError::DataTooLong);
+
+    let item = &mut ctx.accounts.catalog_item;
+    item.platform = ctx.accounts.platform_config.key();
+    item.name = fit_to_array::<MAX_NAME_LEN>(&args.name);
+    item.points_cost = args.points_cost;
+    item.inventory = args.inventory;
+    item.total_redeemed = 0;
+    item.per_wallet_limit = args.per_wallet_limit;
+    item.starts_at = args.starts_at;
+    item.ends_at = args.ends_at;
+    item.active = true;
+    item.bump = ctx.bumps.catalog_item;
+    require!(item.has_valid_window(), DechargeError::InvalidRedemptionWindow);
+
+    emit!(CatalogItemUpdated {
+        item: item.key(),
+        points_cost: item.points_cost,
+        inventory: item.inventory,
+        active: item.active,
+    });
+
+    Ok(())
+}
+
+pub fn update_catalog_item(
+    ctx: Context<UpdateCatalogItem>,
+    args: UpdateCatalogItemArgs,
+) -> Result<()> {
+    let item = &mut ctx.accounts.catalog_item;
+    if let Some(points_cost) = args.points_cost {
+        item.points_cost = points_cost;
+    }
+    if let Some(inventory) = args.inventory {
+        item.inventory = inventory;
+    }
+    if let Some(per_wallet_limit) = args.per_wallet_limit {
+        item.per_wallet_limit = per_wallet_limit;
+    }
+    if let Some(starts_at) = args.starts_at {
+        item.starts_at = starts_at;
+    }
+    if let Some(ends_at) = args.ends_at {
+        item.ends_at = ends_at;
+    }
+    if let Some(active) = args.active {
+        item.active = active;
+    }
+    require!(item.has_valid_window(), DechargeError::InvalidRedemptionWindow);
+
+    emit!(CatalogItemUpdated {
+        item: item.key(),
+        points_cost: item.points_cost,
+        inventory: item.inventory,
+        active: item.active,
+    });
+
+    Ok(())
+}
+
+pub fn redeem_item(ctx: Context<RedeemItem>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let item = &mut ctx.accounts.catalog_item;
+    require!(item.is_available(now), DechargeError::ItemUnavailable);
+    require!(item.inventory > 0, DechargeError::ItemSoldOut);
+
+    let redemption = &mut ctx.accounts.item_redemption;
+    if redemption.holder == Pubkey::default() {
+        redemption.item = item.key();
+        redemption.holder = ctx.accounts.holder.key();
+        redemption.bump = ctx.bumps.item_redemption;
+    }
+    require!(
+        item.per_wallet_limit == 0 || redemption.count < item.per_wallet_limit,
+        DechargeError::RedemptionLimitReached
+    );
+
+    let burn_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Burn {
+            mint: ctx.accounts.point_mint.to_account_info(),
+            from: ctx.accounts.holder_token_account.to_account_info(),
+            authority: ctx.accounts.holder.to_account_info(),
+        },
+    );
+    token_interface::burn(burn_ctx, item.points_cost)?;
+
+    let voucher_id = u64::from(item.total_redeemed);
+    item.inventory -= 1;
+    item.total_redeemed = item
+        .total_redeemed
+        .checked_add(1)
+        .ok_or(DechargeError::NumericalOverflow)?;
+    redemption.count = redemption
+        .count
+        .checked_add(1)
+        .ok_or(DechargeError::NumericalOverflow)?;
+
+    emit!(ItemRedeemed {
+        item: item.key(),
+        holder: redemption.holder,
+        voucher_id,
+        points_burned: item.points_cost,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+pub fn initialize_world(ctx: Context<InitializeWorld>, args: InitializeWorldArgs) -> Result<()> {
+    require!(
+        u64::from(args.owner_revenue_bps) <= BPS_DENOMINATOR,
+        DechargeError::InvalidBasisPoints
+    );
+    require!(
+        args.peak_start_hour < 24 && args.peak_end_hour < 24,
+        DechargeError::InvalidPeakHours
+    );
+    require!(args.boost_duration_seconds > 0, DechargeError::InvalidBoostDuration);
+    require!(
+        WorldConfig::level_prices_ascending(&args.level_prices_lamports),
+        DechargeError::InvalidLevelPrices
+    );
+    require!(
+        args.min_x <= args.max_x && args.min_y <= args.max_y,
+        DechargeError::InvalidWorldBounds
+    );
+
+    let world_config = &mut ctx.accounts.world_config;
+    world_config.platform = ctx.accounts.platform_config.key();
+    world_config.max_plots = args.max_plots;
+    world_config.defined_plots = 0;
+    world_config.claimed_plots = 0;
+    world_config.bump = ctx.bumps.world_config;
+    world_config.level_prices_lamports = args.level_prices_lamports;
+    world_config.boost_prices_lamports = args.boost_prices_lamports;
+    world_config.boost_bps = args.boost_bps;
+    world_config.boost_duration_seconds = args.boost_duration_seconds;
+    world_config.peak_start_hour = args.peak_start_hour;
+    world_config.peak_end_hour = args.peak_end_hour;
+    world_config.level_multiplier_bps = args.level_multiplier_bps;
+    world_config.owner_revenue_bps = args.owner_revenue_bps;
+    world_config.plot_reward_points_per_slot_day = args.plot_reward_points_per_slot_day;
+    world_config.reward_schedule_start = Clock::get()?.unix_timestamp;
+    world_config.reward_halving_seconds = args.reward_halving_seconds;
+    world_config.min_x = args.min_x;
+    world_config.min_y = args.min_y;
+    world_config.max_x = args.max_x;
+    world_config.max_y = args.max_y;
+
+    Ok(())
+}
+
+pub fn update_world_config(
+    ctx: Context<UpdateWorldConfig>,
+    args: UpdateWorldConfigArgs,
+) -> Result<()> {
+    let world_config = &mut ctx.accounts.world_config;
+    if let Some(max_plots) = args.max_plots {
+        require!(
+            max_plots >= world_config.defined_plots,
+            DechargeError::PlotSupplyTooLow
+        );
+        world_config.max_plots = max_plots;
+    }
+    if let Some(level_prices_lamports) = args.level_prices_lamports {
+        require!(
+            WorldConfig::level_prices_ascending(&level_prices_lamports),
+            DechargeError::InvalidLevelPrices
+        );
+        world_config.level_prices_lamports = level_prices_lamports;
+    }
+    if let Some(boost_prices_lamports) = args.boost_prices_lamports {
+        world_config.boost_prices_lamports = boost_prices_lamports;
+    }
+    if let Some(boost_bps) = args.boost_bps {
+        world_config.boost_bps = boost_bps;
+    }
+    if let Some(boost_duration_seconds) = args.boost_duration_seconds {
+        require!(boost_duration_seconds > 0, DechargeError::InvalidBoostDuration);
+        world_config.boost_duration_seconds = boost_duration_seconds;
+    }
+    if let Some((peak_start_hour, peak_end_hour)) = args.peak_hours {
+        require!(
+            peak_start_hour < 24 && peak_end_hour < 24,
+            DechargeError::InvalidPeakHours
+        );
+        world_config.peak_start_hour = peak_start_hour;
+        world_config.peak_end_hour = peak_end_hour;
+    }
+    if let Some(owner_revenue_bps) = args.owner_revenue_bps {
+        require!(
+            u64::from(owner_revenue_bps) <= BPS_DENOMINATOR,
+            DechargeError::InvalidBasisPoints
+        );
+        world_config.owner_revenue_bps = owner_revenue_bps;
+    }
+
+    Ok(())
+}
+
+pub fn create_region(ctx: Context<CreateRegion>, args: CreateRegionArgs) -> Result<()> {
+    require!(args.key.len() <= MAX_SEED_LEN, DechargeError::DataTooLong);
+    require!(
+        args.min_x <= args.max_x && args.min_y <= args.max_y,
+        DechargeError::InvalidRegionBounds
+    );
+
+    let region = &mut ctx.accounts.region;
+    region.key = fit_to_array::<MAX_NAME_LEN>(&args.key);
+    region.min_x = args.min_x;
+    region.min_y = args.min_y;
+    region.max_x = args.max_x;
+    region.max_y = args.max_y;
+    region.max_plots = args.max_plots;
+    region.defined_plots = 0;
+    region.claimed_plots = 0;
+    region.base_reward_points = args.base_reward_points;
+    region.bump = ctx.bumps.region;
+
+    require!(
+        ctx.accounts.world_config.contains_region(region),
+        DechargeError::RegionOutOfBounds
+    );
+
+    emit!(RegionCreated {
+        region: region.key(),
+        key: args.key,
+        max_plots: region.max_plots,
+        base_reward_points: region.base_reward_points,
+    });
+
+    Ok(())
+}
+
+pub fn define_plot(ctx: Context<DefinePlot>, x: i32, y: i32) -> Result<()> {
+    let region = &mut ctx.accounts.region;
+    require!(region.contains(x, y), DechargeError::PlotOutOfBounds);
+    require!(region.defined_plots < region.max_plots, DechargeError::RegionFull);
+    let world_config = &mut ctx.accounts.world_config;
+    require!(world_config.defined_plots < world_config.max_plots, DechargeError::WorldFull);
+
+    region.defined_plots += 1;
+    world_config.defined_plots += 1;
+
+    let plot = &mut ctx.accounts.plot;
+    plot.owner = Pubkey::default();
+    plot.region_key = region.key;
+    plot.slot_capacity = 0;
+    plot.upgrade_level = 0;
+    plot.last_reward_time = 0;
+    plot.bump = ctx.bumps.plot;
+    plot.region = region.key();
+    plot.x = x;
+    plot.y = y;
+
+    emit!(PlotDefined {
+        plot: plot.key(),
+        region: plot.region,
+        x,
+        y,
+    });
+
+    Ok(())
+}
+
+pub fn claim_world_plot(ctx: Context<ClaimWorldPlot>) -> Result<()> {
+    let fee_lamports = ctx.accounts.platform_config.plot_claim_fee_lamports;
+    let fee_points = ctx.accounts.platform_config.plot_claim_fee_points;
+    pay_world_treasury_lamports(
+        &ctx.accounts.claimant,
+        &ctx.accounts.world_treasury,
+        &ctx.accounts.system_program,
+        fee_lamports,
+    )?;
+    pay_world_treasury_points(
+        &ctx.accounts.claimant,
+        &ctx.accounts.world_treasury,
+        &ctx.accounts.claimant_token_account,
+        &ctx.accounts.treasury_token_account,
+        &ctx.accounts.point_mint,
+        &ctx.accounts.token_program,
+        fee_points,
+    )?;
+
+    ctx.accounts.region.claimed_plots += 1;
+    ctx.accounts.world_config.claimed_plots += 1;
+
+    let plot = &mut ctx.accounts.plot;
+    plot.owner = ctx.accounts.claimant.key();
+    plot.slot_capacity = 1;
+    plot.upgrade_level = 1;
+    plot.last_reward_time = Clock::get()?.unix_timestamp;
+
+    emit!(PlotClaimed {
+        plot: plot.key(),
+        owner: plot.owner,
+        region: String::from_utf8_lossy(&plot.region_key).trim_end_matches('\0').to_string(),
+        fee_lamports,
+        fee_points,
+    });
+
+    Ok(())
+}
+
+pub fn upgrade_plot(ctx: Context<UpgradePlot>, target_level: u8) -> Result<()> {
+    let from_level = ctx.accounts.plot.upgrade_level;
+    require!(
+        target_level > from_level && target_level <= MAX_PLOT_LEVEL,
+        DechargeError::InvalidUpgradeLevel
+    );
+
+    let price_lamports = ctx
+        .accounts
+        .world_config
+        .upgrade_price(from_level, target_level)
+        .ok_or(DechargeError::NumericalOverflow)?;
+    pay_world_treasury_lamports(
+        &ctx.accounts.owner,
+        &ctx.accounts.world_treasury,
+        &ctx.accounts.system_program,
+        price_lamports,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let grid_sync = ctx.accounts.grid_sync_boost.as_deref().map(|boost| &**boost);
+    let plot = &mut ctx.accounts.plot;
+    plot.settle_rewards(&ctx.accounts.world_config, &ctx.accounts.region, grid_sync, now)?;
+    plot.upgrade_level = target_level;
+
+    emit!(PlotUpgraded {
+        plot: plot.key(),
+        owner: plot.owner,
+        from_level,
+        to_level: target_level,
+        price_lamports,
+    });
+
+    Ok(())
+}
+
+pub fn purchase_plot_boost(
+    ctx: Context<PurchasePlotBoost>,
+    args: PurchasePlotBoostArgs,
+) -> Result<()> {
+    let kind = args.kind;
+    let now = Clock::get()?.unix_timestamp;
+    let world_config = &ctx.accounts.world_config;
+    let price_lamports = world_config.boost_prices_lamports[kind.index()];
+    pay_world_treasury_lamports(
+        &ctx.accounts.owner,
+        &ctx.accounts.world_treasury,
+        &ctx.accounts.system_program,
+        price_lamports,
+    )?;
+
+    let grid_sync = match kind {
+        BoostKind::GridSync => Some(&*ctx.accounts.boost),
+        _ => ctx.accounts.grid_sync_boost.as_deref().map(|boost| &**boost),
+    };
+    ctx.accounts
+        .plot
+        .settle_rewards(world_config, &ctx.accounts.region, grid_sync, now)?;
+
+    let boost = &mut ctx.accounts.boost;
+    let starts_at = if boost.plot == Pubkey::default() {
+        boost.plot = ctx.accounts.plot.key();
+        boost.kind = kind;
+        boost.bump = ctx.bumps.boost;
+        now
+    } else {
+        boost.expires_at.max(now)
+    };
+    boost.magnitude_bps = world_config.boost_bps[kind.index()];
+    boost.expires_at = starts_at
+        .checked_add(world_config.boost_duration_seconds)
+        .ok_or(DechargeError::NumericalOverflow)?;
+
+    emit!(PlotBoostPurchased {
+        plot: boost.plot,
+        kind,
+        magnitude_bps: boost.magnitude_bps,
+        expires_at: boost.expires_at,
+        price_lamports,
+    });
+
+    Ok(())
+}
+
+pub fn record_virtual_session(
+    ctx: Context<RecordVirtualSession>,
+    args: VirtualSessionArgs,
+) -> Result<()> {
+    require!(args.energy_wh > 0, DechargeError::InvalidTelemetry);
+
+    let now = Clock::get()?.unix_timestamp;
+    let config = &ctx.accounts.platform_config;
+    let world_config = &ctx.accounts.world_config;
+    let boost_bps = [&ctx.accounts.solar_boost, &ctx.accounts.community_boost]
+        .into_iter()
+        .flatten()
+        .map(|boost| boost.bonus_bps(world_config, now))
+        .sum::<u64>();
+
+    let plot = &mut ctx.accounts.plot;
+    let session_points = world_config.virtual_session_points(
+        args.energy_wh,
+        config.point_rate_microunits,
+        plot.upgrade_level,
+        boost_bps,
+    )?;
+
+    let mut emissions =
+        budgeted_emissions(ctx.accounts.point_emissions.as_deref_mut(), config, now)?;
+    let was_near_cap = emissions.as_deref().is_some_and(|emissions| emissions.near_cap(config));
+    let owner_share = world_config.owner_revenue(session_points);
+    let owner_points = allocate_emissions(emissions.as_deref_mut(), config, owner_share)?;
+    let owner_points_deferred = owner_share - owner_points;
+
+    let virtual_session = &mut ctx.accounts.virtual_session;
+    virtual_session.plot = plot.key();
+    virtual_session.session_id = args.session_id;
+    virtual_session.energy_wh = args.energy_wh;
+    virtual_session.owner_points = owner_points;
+    virtual_session.recorded_at = now;
+    virtual_session.bump = ctx.bumps.virtual_session;
+
+    plot.virtual_sessions = plot
+        .virtual_sessions
+        .checked_add(1)
+        .ok_or(DechargeError::NumericalOverflow)?;
+    plot.virtual_energy_wh = plot
+        .virtual_energy_wh
+        .checked_add(args.energy_wh)
+        .ok_or(DechargeError::NumericalOverflow)?;
+    plot.owner_points_earned = plot
+        .owner_points_earned
+        .checked_add(owner_points)
+        .ok_or(DechargeError::NumericalOverflow)?;
+    plot.deferred_reward_points = plot
+        .deferred_reward_points
+        .checked_add(owner_points_deferred)
+        .ok_or(DechargeError::NumericalOverflow)?;
+
+    mint_platform_points(
+        &ctx.accounts.token_program,
+        &ctx.accounts.point_mint,
+        &ctx.accounts.owner_token_account,
+        &ctx.accounts.platform_config,
+        owner_points,
+    )?;
+
+    warn_emission_budget(emissions.as_deref(), config, was_near_cap);
+
+    let plot = &ctx.accounts.plot;
+    emit!(VirtualSessionRecorded {
+        plot: plot.key(),
+        owner: plot.owner,
+        session_id: args.session_id,
+        energy_wh: args.energy_wh,
+        upgrade_level: plot.upgrade_level,
+        boost_bps,
+        session_points,
+        owner_points,
+        owner_points_deferred,
+    });
+
+    Ok(())
+}
+
+pub fn claim_plot_rewards(ctx: Context<ClaimPlotRewards>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = &ctx.accounts.platform_config;
+    let world_config = &ctx.accounts.world_config;
+    let grid_sync = ctx.accounts.grid_sync_boost.as_deref().map(|boost| &**boost);
+
+    let plot = &mut ctx.accounts.plot;
+    let elapsed_seconds = now.saturating_sub(plot.last_reward_time).max(0);
+    let points_owed = plot
+        .accrued_rewards(world_config, &ctx.accounts.region, grid_sync, now)?
+        .checked_add(plot.deferred_reward_points)
+        .ok_or(DechargeError::NumericalOverflow)?;
+    require!(points_owed > 0, DechargeError::NothingToClaim);
+
+    let mut emissions =
+        budgeted_emissions(ctx.accounts.point_emissions.as_deref_mut(), config, now)?;
+    let was_near_cap = emissions.as_deref().is_some_and(|emissions| emissions.near_cap(config));
+    let points = allocate_emissions(emissions.as_deref_mut(), config, points_owed)?;
+
+    plot.deferred_reward_points = points_owed - points;
+    plot.last_reward_time = now;
+
+    mint_platform_points(
+        &ctx.accounts.token_program,
+        &ctx.accounts.point_mint,
+        &ctx.accounts.owner_token_account,
+        &ctx.accounts.platform_config,
+        points,
+    )?;
+
+    warn_emission_budget(emissions.as_deref(), config, was_near_cap);
+
+    let plot = &ctx.accounts.plot;
+    emit!(PlotRewardsClaimed {
+        plot: plot.key(),
+        owner: plot.owner,
+        elapsed_seconds,
+        points,
+        points_deferred: plot.deferred_reward_points,
+    });
+
+    Ok(())
+}
+
+fn write_platform_config(
+    config: &mut PlatformConfig,
+    admin: Pubkey,
+    point_mint: Pubkey,
+    payment_treasury: Pubkey,
+    world_treasury: Pubkey,
+    bump: u8,
+    args: InitializePlatformArgs,
+) -> Result<()> {
+    require!(
+        args.points_expiry_epochs as usize <= MAX_POINT_BUCKETS,
+        DechargeError::InvalidExpiryHorizon
+    );
+    require!(
+        u64::from(args.emission_warning_bps) <= BPS_DENOMINATOR,
+        DechargeError::InvalidBasisPoints
+    );
+    require!(
+        args.epoch_emission_budget == 0 || args.points_epoch_seconds > 0,
+        DechargeError::EmissionEpochRequired
+    );
+
+    config.admin = admin;
+    config.oracle = args.oracle;
+    config.point_mint = point_mint;
+    config.bump = bump;
+    config.point_rate_microunits = args.point_rate_microunits;
+    config.payment_treasury = payment_treasury;
+    config.world_treasury = world_treasury;
+    config.ice_baseline_g_per_kwh = args.ice_baseline_g_per_kwh;
+    config.points_epoch_seconds = args.points_epoch_seconds;
+    config.points_epoch_start = Clock::get()?.unix_timestamp;
+    config.points_expiry_epochs = args.points_expiry_epochs;
+    config.loyalty_tiers = args.loyalty_tiers;
+    config.tier_window_seconds = args.tier_window_seconds;
+    config.referral_threshold_wh = args.referral_threshold_wh;
+    config.referrer_bonus_points = args.referrer_bonus_points;
+    config.referee_bonus_points = args.referee_bonus_points;
+    config.max_referrals_per_referrer = args.max_referrals_per_referrer;
+    config.epoch_emission_budget = args.epoch_emission_budget;
+    config.lifetime_emission_budget = args.lifetime_emission_budget;
+    config.emission_warning_bps = args.emission_warning_bps;
+    config.daily_transfer_limit = args.daily_transfer_limit;
+    config.governance_quorum_power = args.governance_quorum_power;
+    config.governance_voting_seconds = args.governance_voting_seconds;
+    config.plot_claim_fee_lamports = args.plot_claim_fee_lamports;
+    config.plot_claim_fee_points = args.plot_claim_fee_points;
+
+    Ok(())
+}
+
+/// Tops up `account` so it stays rent exempt once it grows to `data_len` bytes.
+fn fund_rent_exemption<'info>(
+    payer: &Signer<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    data_len: usize,
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(data_len);
+    let shortfall = required_lamports.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        invoke(
+            &system_instruction::transfer(&payer.key(), &account.key(), shortfall),
+            &[
+                payer.to_account_info(),
+                account.clone(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+fn pay_world_treasury_lamports<'info>(
+    payer: &Signer<'info>,
+    world_treasury: &SystemAccount<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    invoke(
+        &system_instruction::transfer(&payer.key(), &world_treasury.key(), amount),
+        &[
+            payer.to_account_info(),
+            world_treasury.to_account_info(),
+            system_program.to_account_info(),
+        ],
+    )?;
+    Ok(())
+}
+
+/// Moves `amount` points from the payer's token account to the world treasury's.
+fn pay_world_treasury_points<'info>(
+    payer: &Signer<'info>,
+    world_treasury: &SystemAccount<'info>,
+    payer_token_account: &Option<InterfaceAccount<'info, TokenAccount>>,
+    treasury_token_account: &Option<InterfaceAccount<'info, TokenAccount>>,
+    point_mint: &Option<InterfaceAccount<'info, Mint>>,
+    token_program: &Option<Interface<'info, TokenInterface>>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let (
+        Some(payer_token_account),
+        Some(treasury_token_account),
+        Some(point_mint),
+        Some(token_program),
+    ) = (payer_token_account, treasury_token_account, point_mint, token_program)
+    else {
+        return err!(DechargeError::MissingFeeAccounts);
+    };
+    require_keys_eq!(
+        treasury_token_account.owner,
+        world_treasury.key(),
+        DechargeError::TreasuryMismatch
+    );
+
+    let transfer_ctx = CpiContext::new(
+        token_program.to_account_info(),
+        TransferChecked {
+            from: payer_token_account.to_account_info(),
+            mint: point_mint.to_account_info(),
+            to: treasury_token_account.to_account_info(),
+            authority: payer.to_account_info(),
+        },
+    );
+    token_interface::transfer_checked(transfer_ctx, amount, point_mint.decimals)
+}
+
+/// Mints `amount` points to `to`, signed by the platform PDA as mint authority.
+fn mint_platform_points<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    point_mint: &InterfaceAccount<'info, Mint>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    platform_config: &Account<'info, PlatformConfig>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let mint_seeds: &[&[u8]] = &[PLATFORM_SEED, &[platform_config.bump]];
+    let signer_seeds = &[mint_seeds];
+    let mint_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        MintTo {
+            mint: point_mint.to_account_info(),
+            to: to.to_account_info(),
+            authority: platform_config.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::mint_to(mint_ctx, amount)
+}
+
+/// Returns the emissions account rolled to the current epoch while a budget is set.
+/// Without a budget nothing is tracked, so mints do not contend on the shared account.
+fn budgeted_emissions<'a>(
+    point_emissions: Option<&'a mut Account<'_, PointEmissions>>,
+    config: &PlatformConfig,
+    now: i64,
+) -> Result<Option<&'a mut PointEmissions>> {
+    if !config.has_emission_budget() {
+        return Ok(None);
+    }
+    let emissions = point_emissions.ok_or(DechargeError::MissingEmissionsAccount)?;
+    emissions.roll_epoch(config.points_epoch(now));
+    Ok(Some(&mut **emissions))
+}
+
+/// Charges `requested` against the budgets, granting all of it when none is set.
+fn allocate_emissions(
+    emissions: Option<&mut PointEmissions>,
+    config: &PlatformConfig,
+    requested: u64,
+) -> Result<u64> {
+    match emissions {
+        Some(emissions) => emissions.allocate(config, requested),
+        None => Ok(requested),
+    }
+}
+
+/// Emits `EmissionBudgetWarning` when this instruction pushed emissions near the cap.
+fn warn_emission_budget(
+    emissions: Option<&PointEmissions>,
+    config: &PlatformConfig,
+    was_near_cap: bool,
+) {
+    let Some(emissions) = emissions else {
+        return;
+    };
+    if !was_near_cap && emissions.near_cap(config) {
+        emit!(EmissionBudgetWarning {
+            epoch: emissions.epoch,
+            epoch_minted: emissions.epoch_minted,
+            epoch_budget: config.epoch_emission_budget,
+            lifetime_minted: emissions.lifetime_minted,
+            lifetime_budget: config.lifetime_emission_budget,
+        });
+    }
+}
+
+/// Mints a single non-transferable Token-2022 receipt for the session to the driver's
+/// associated token account, then drops the mint authority so the supply stays at one.
+fn mint_receipt_token(accounts: &CloseSession, mint_bump: u8) -> Result<()> {
+    let (Some(receipt_mint), Some(driver), Some(driver_receipt_account), Some(token_program)) = (
+        accounts.receipt_mint.as_ref(),
+        accounts.driver.as_ref(),
+        accounts.driver_receipt_account.as_ref(),
+        accounts.receipt_token_program.as_ref(),
+    ) else {
+        return err!(DechargeError::MissingReceiptAccounts);
+    };
+
+    let session_key = accounts.session.key();
+    let mint_seeds: &[&[u8]] = &[RECEIPT_MINT_SEED, session_key.as_ref(), &[mint_bump]];
+    let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::NonTransferable,
+    ])?;
+    create_account(
+        CpiContext::new_with_signer(
+            accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: accounts.oracle.to_account_info(),
+                to: receipt_mint.to_account_info(),
+            },
+            &[mint_seeds],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &token_program.key(),
+    )?;
+    token_interface::non_transferable_mint_initialize(CpiContext::new(
+        token_program.to_account_info(),
+        NonTransferableMintInitialize {
+            token_program_id: token_program.to_account_info(),
+            mint: receipt_mint.to_account_info(),
+        },
+    ))?;
+    token_interface::initialize_mint2(
+        CpiContext::new(
+            token_program.to_account_info(),
+            InitializeMint2 {
+                mint: receipt_mint.to_account_info(),
+            },
+        ),
+        0,
+        &accounts.platform_config.key(),
+        None,
+    )?;
+    associated_token::create(CpiContext::new(
+        accounts.associated_token_program.to_account_info(),
+        associated_token::Create {
+            payer: accounts.oracle.to_account_info(),
+            associated_token: driver_receipt_account.to_account_info(),
+            authority: driver.to_account_info(),
+            mint: receipt_mint.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+        },
+    ))?;
+
+    let config_seeds: &[&[u8]] = &[PLATFORM_SEED, &[accounts.platform_config.bump]];
+    let signer_seeds = &[config_seeds];
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: receipt_mint.to_account_info(),
+                to: driver_receipt_account.to_account_info(),
+                authority: accounts.platform_config.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+    token_interface::set_authority(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            SetAuthority {
+                current_authority: accounts.platform_config.to_account_info(),
+                account_or_mint: receipt_mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )
+}
+
+fn track_soc(session: &mut Account<ChargingSession>, soc_percent: u8) -> Result<()> {
+    let target_reached = session.record_soc(soc_percent)?;
+    if target_reached && session.auto_close_at_target && !session.stop_requested {
+        session.stop_requested = true;
+        emit!(TargetSocReached {
+            session: session.key(),
+            soc_percent,
+        });
+    }
+    Ok(())
+}
+
+fn session_vehicle<'a>(
+    session: &ChargingSession,
+    vehicle: Option<&'a Account<Vehicle>>,
+) -> Result<Option<&'a Vehicle>> {
+    if session.vehicle == Pubkey::default() {
+        return Ok(None);
+    }
+    let vehicle = vehicle.ok_or(DechargeError::VehicleMismatch)?;
+    require_keys_eq!(vehicle.key(), session.vehicle, DechargeError::VehicleMismatch);
+    Ok(Some(vehicle))
+}
+
+#[derive(Accounts)]
+#[instruction(args: InitializePlatformArgs)]
+pub struct InitializePlatform<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        init,
+        payer = admin,
+        seeds = [PLATFORM_SEED],
+        bump,
+        space = PlatformConfig::LEN,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        init,
+        payer = admin,
+        seeds = [POINT_EMISSIONS_SEED],
+        bump,
+        space = PointEmissions::LEN,
+    )]
+    pub point_emissions: Account<'info, PointEmissions>,
+    #[account(
+        init,
+        payer = admin,
+        mint::decimals = DEFAULT_POINTS_DECIMALS,
+        mint::authority = platform_config,
+        mint::freeze_authority = platform_config,
+        mint::token_program = token_program,
+    )]
+    pub point_mint: InterfaceAccount<'info, Mint>,
+    /// Treasury account where SOL/USDC payments are routed
+    #[account(mut)]
+    pub payment_treasury: SystemAccount<'info>,
+    /// Treasury for world rewards
+    #[account(mut)]
+    pub world_treasury: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InitializePlatformArgs {
+    pub oracle: Pubkey,
+    pub point_rate_microunits: u64,
+    pub ice_baseline_g_per_kwh: u32,
+    pub points_epoch_seconds: i64,
+    pub points_expiry_epochs: u32,
+    pub loyalty_tiers: [LoyaltyTier; MAX_LOYALTY_TIERS],
+    pub tier_window_seconds: i64,
+    pub referral_threshold_wh: u64,
+    pub referrer_bonus_points: u64,
+    pub referee_bonus_points: u64,
+    pub max_referrals_per_referrer: u32,
+    pub epoch_emission_budget: u64,
+    pub lifetime_emission_budget: u64,
+    pub emission_warning_bps: u16,
+    pub daily_transfer_limit: u64,
+    pub governance_quorum_power: u64,
+    pub governance_voting_seconds: i64,
+    pub plot_claim_fee_lamports: u64,
+    pub plot_claim_fee_points: u64,
+}
+
+#[derive(Accounts)]
+pub struct InitializePlatformWithMetadata<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        init,
+        payer = admin,
+        seeds = [PLATFORM_SEED],
+        bump,
+        space = PlatformConfig::LEN,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    #[account(
+        init,
+        payer = admin,
+        seeds = [POINT_EMISSIONS_SEED],
+        bump,
+        space = PointEmissions::LEN,
+    )]
+    pub point_emissions: Box<Account<'info, PointEmissions>>,
+    #[account(
+        init,
+        payer = admin,
+        mint::decimals = DEFAULT_POINTS_DECIMALS,
+        mint::authority = platform_config,
+        mint::freeze_authority = platform_config,
+        mint::token_program = token_program,
+        extensions::metadata_pointer::authority = platform_config,
+        extensions::metadata_pointer::metadata_address = point_mint,
+    )]
+    pub point_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// Treasury account where SOL/USDC payments are routed
+    #[account(mut)]
+    pub payment_treasury: SystemAccount<'info>,
+    /// Treasury for world rewards
+    #[account(mut)]
+    pub world_treasury: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PointMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+impl PointMetadataArgs {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.name.len() <= MAX_NAME_LEN, DechargeError::DataTooLong);
+        require!(self.symbol.len() <= MAX_SYMBOL_LEN, DechargeError::DataTooLong);
+        require!(self.uri.len() <= MAX_URI_LEN, DechargeError::DataTooLong);
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct UpdatePointMetadata<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ DechargeError::Unauthorized,
+        has_one = point_mint,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut, mint::token_program = token_program)]
+    pub point_mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeRecMint<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ DechargeError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        init,
+        payer = admin,
+        seeds = [REC_MINT_SEED],
+        bump,
+        mint::decimals = REC_DECIMALS,
+        mint::authority = platform_config,
+        mint::freeze_authority = platform_config,
+        mint::token_program = token_program,
+    )]
+    pub rec_mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlatformConfig<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ DechargeError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdatePlatformConfigArgs {
+    pub oracle: Option<Pubkey>,
+    pub point_rate_microunits: Option<u64>,
+    pub ice_baseline_g_per_kwh: Option<u32>,
+    pub points_epoch_seconds: Option<i64>,
+    pub points_expiry_epochs: Option<u32>,
+    pub loyalty_tiers: Option<[LoyaltyTier; MAX_LOYALTY_TIERS]>,
+    pub tier_window_seconds: Option<i64>,
+    pub referral_threshold_wh: Option<u64>,
+    pub referrer_bonus_points: Option<u64>,
+    pub referee_bonus_points: Option<u64>,
+    pub max_referrals_per_referrer: Option<u32>,
+    pub epoch_emission_budget: Option<u64>,
+    pub lifetime_emission_budget: Option<u64>,
+    pub emission_warning_bps: Option<u16>,
+    pub daily_transfer_limit: Option<u64>,
+    pub governance_quorum_power: Option<u64>,
+    pub governance_voting_seconds: Option<i64>,
+    pub plot_claim_fee_lamports: Option<u64>,
+    pub plot_claim_fee_points: Option<u64>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: RegisterStationArgs)]
+pub struct RegisterStation<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ DechargeError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        init,
+        payer = admin,
+        seeds = [
+            STATION_SEED,
+            args.station_code.as_bytes(),
+        ],
+        bump,
+        space = ChargingStation::LEN,
+    )]
+    pub station: Account<'info, ChargingStation>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RegisterStationArgs {
+    pub station_code: String,
+    pub name: String,
+    pub city: String,
+    pub location_lat_micro: i32,
+    pub location_lon_micro: i32,
+    pub max_kw: u32,
+    pub pricing_energy_microunits: u64,
+    pub pricing_time_microunits: u64,
+    pub uri: String,
+    pub grid_carbon_g_per_kwh: u32,
+    pub renewable_fraction_bps: u16,
+    /// `CONNECTOR_TYPE_*` bitflags; zero accepts any vehicle.
+    pub connector_types: u8,
+}
+
+#[derive(Accounts)]
+pub struct UpdateStationCarbonProfile<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ DechargeError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        constraint = station.platform == platform_config.key() @ DechargeError::Unauthorized,
+    )]
+    pub station: Account<'info, ChargingStation>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct StationCarbonProfileArgs {
+    pub grid_carbon_g_per_kwh: u32,
+    pub renewable_fraction_bps: u16,
+}
+
+#[derive(Accounts)]
+#[instruction(args: RegisterVehicleArgs)]
+pub struct RegisterVehicle<'info> {
+    #[account(mut)]
+    pub driver: Signer<'info>,
+    #[account(
+        init,
+        payer = driver,
+        seeds = [VEHICLE_SEED, driver.key().as_ref(), args.vin_hash.as_ref()],
+        bump,
+        space = Vehicle::LEN,
+    )]
+    pub vehicle: Account<'info, Vehicle>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RegisterVehicleArgs {
+    pub vin_hash: [u8; 32],
+    pub battery_capacity_wh: u64,
+    pub max_ac_kw: u32,
+    pub max_dc_kw: u32,
+    pub connector_types: u8,
+}
+
+#[derive(Accounts)]
+pub struct StartSession<'info> {
+    #[account(mut)]
+    pub oracle: Signer<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = oracle @ DechargeError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub station: Account<'info, ChargingStation>,
+    /// Driver wallet or a session key granted through `driver_delegate`
+    pub driver_authority: Signer<'info>,
+    /// CHECK: must equal `driver_authority` unless a matching delegate is supplied
+    pub driver: UncheckedAccount<'info>,
+    #[account(
+        seeds = [DRIVER_DELEGATE_SEED, driver.key().as_ref(), driver_authority.key().as_ref()],
+        bump = driver_delegate.bump,
+    )]
+    pub driver_delegate: Option<Account<'info, DriverDelegate>>,
+    #[account(constraint = vehicle.owner == driver.key() @ DechargeError::VehicleMismatch)]
+    pub vehicle: Option<Account<'info, Vehicle>>,
+    /// Recorded as the referrer when the driver profile is first created
+    #[account(
+        seeds = [DRIVER_PROFILE_SEED, referrer_profile.driver.as_ref()],
+        bump = referrer_profile.bump,
+    )]
+    pub referrer_profile: Option<Account<'info, DriverProfile>>,
+    #[account(
+        init_if_needed,
+        payer = oracle,
+        seeds = [DRIVER_PROFILE_SEED, driver.key().as_ref()],
+        bump,
+        space = DriverProfile::LEN,
+    )]
+    pub driver_profile: Account<'info, DriverProfile>,
+    #[account(
+        init,
+        payer = oracle,
+        seeds = [
+            SESSION_SEED,
+            station.key().as_ref(),
+            station.next_session.to_le_bytes().as_ref(),
+        ],
+        bump,
+        space = ChargingSession::LEN,
+    )]
+    pub session: Account<'info, ChargingSession>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct StartSessionArgs {
+    pub session_hash: [u8; 32],
+    pub timestamp: i64,
+    pub start_soc_percent: Option<u8>,
+    pub target_soc_percent: Option<u8>,
+    pub auto_close_at_target: bool,
+}
+
+#[derive(Accounts)]
+pub struct RequestStopSession<'info> {
+    /// Driver wallet or a session key granted through `driver_delegate`
+    pub driver_authority: Signer<'info>,
+    #[account(mut)]
+    pub session: Account<'info, ChargingSession>,
+    #[account(
+        seeds = [DRIVER_DELEGATE_SEED, session.driver.as_ref(), driver_authority.key().as_ref()],
+        bump = driver_delegate.bump,
+    )]
+    pub driver_delegate: Option<Account<'info, DriverDelegate>>,
+}
+
+#[derive(Accounts)]
+pub struct RecordTelemetry<'info> {
+    #[account(mut)]
+    pub oracle: Signer<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = oracle @ DechargeError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub session: Account<'info, ChargingSession>,
+    /// Required when the session declared a vehicle
+    pub vehicle: Option<Account<'info, Vehicle>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TelemetryArgs {
+    pub energy_delta_wh: u64,
+    pub seconds_delta: u64,
+    pub telemetry_hash: [u8; 32],
+    pub soc_percent: Option<u8>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeTelemetryLog<'info> {
+    #[account(mut)]
+    pub oracle: Signer<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = oracle @ DechargeError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    pub session: Account<'info, ChargingSession>,
+    #[account(
+        init,
+        payer = oracle,
+        seeds = [TELEMETRY_LOG_SEED, session.key().as_ref()],
+        bump,
+        space = SessionTelemetryLog::LEN,
+    )]
+    pub telemetry_log: AccountLoader<'info, SessionTelemetryLog>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RecordTelemetryBatch<'info> {
+    pub oracle: Signer<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = oracle @ DechargeError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub session: Account<'info, ChargingSession>,
+    #[account(
+        mut,
+        seeds = [TELEMETRY_LOG_SEED, session.key().as_ref()],
+        bump = telemetry_log.load()?.bump,
+    )]
+    pub telemetry_log: AccountLoader<'info, SessionTelemetryLog>,
+    /// Required when the session declared a vehicle
+    pub vehicle: Option<Account<'info, Vehicle>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TelemetrySampleArgs {
+    pub timestamp: i64,
+    pub cumulative_wh: u64,
+    pub power_watts: u32,
+    pub soc_percent: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TelemetryBatchArgs {
+    pub samples: Vec<TelemetrySampleArgs>,
+    pub telemetry_hash: [u8; 32],
+}
+
+#[derive(Accounts)]
+pub struct CloseSession<'info> {
+    #[account(mut)]
+    pub oracle: Signer<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = oracle @ DechargeError::Unauthorized,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    #[account(
+        mut,
+        has_one = station,
+        constraint = session.driver == driver_profile.driver @ DechargeError::Unauthorized,
+    )]
+    pub session: Box<Account<'info, ChargingSession>>,
+    #[account(
+        mut,
+        seeds = [DRIVER_PROFILE_SEED, driver_profile.driver.as_ref()],
+        bump = driver_profile.bump,
+    )]
+    pub driver_profile: Box<Account<'info, DriverProfile>>,
+    #[account(mut)]
+    pub station: Box<Account<'info, ChargingStation>>,
+    #[account(
+        init_if_needed,
+        payer = oracle,
+        seeds = [POINTS_VAULT_SEED, driver_profile.driver.as_ref()],
+        bump,
+        space = PointsVault::LEN,
+    )]
+    pub points_vault: Box<Account<'info, PointsVault>>,
+    #[account(
+        init_if_needed,
+        payer = oracle,
+        associated_token::mint = point_mint,
+        associated_token::authority = points_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, address = platform_config.point_mint)]
+    pub point_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: delegate that opened the session, required when it has one; charged with the
+    /// session price unless it has since been revoked and closed
+    #[account(mut, address = session.delegate @ DechargeError::Unauthorized)]
+    pub driver_delegate: Option<UncheckedAccount<'info>>,
+    /// Required when the session declared a vehicle
+    pub vehicle: Option<Box<Account<'info, Vehicle>>>,
+    /// Supply to issue a receipt for the closed session
+    #[account(
+        init,
+        payer = oracle,
+        seeds = [SESSION_RECEIPT_SEED, session.key().as_ref()],
+        bump,
+        space = SessionReceipt::LEN,
+    )]
+    pub session_receipt: Option<Box<Account<'info, SessionReceipt>>>,
+    /// CHECK: non-transferable receipt mint, created with `session_receipt`
+    #[account(mut, seeds = [RECEIPT_MINT_SEED, session.key().as_ref()], bump)]
+    pub receipt_mint: Option<UncheckedAccount<'info>>,
+    /// CHECK: driver wallet that receives the receipt token
+    #[account(address = session.driver @ DechargeError::Unauthorized)]
+    pub driver: Option<UncheckedAccount<'info>>,
+    /// CHECK: driver's receipt token account, created and checked by the associated token
+    /// program
+    #[account(mut)]
+    pub driver_receipt_account: Option<UncheckedAccount<'info>>,
+    pub receipt_token_program: Option<Program<'info, Token2022>>,
+    #[account(
+        init_if_needed,
+        payer = oracle,
+        seeds = [OPERATOR_RECS_SEED, station.operator.as_ref()],
+        bump,
+        space = OperatorRecs::LEN,
+    )]
+    pub operator_recs: Box<Account<'info, OperatorRecs>>,
+    /// Required while an emission budget is set
+    #[account(
+        mut,
+        seeds = [POINT_EMISSIONS_SEED],
+        bump = point_emissions.bump,
+    )]
+    pub point_emissions: Option<Box<Account<'info, PointEmissions>>>,
+    /// Referrer accounts, required to pay out a due referral bonus
+    #[account(
+        mut,
+        seeds = [DRIVER_PROFILE_SEED, driver_profile.referrer.as_ref()],
+        bump = referrer_profile.bump,
+    )]
+    pub referrer_profile: Option<Box<Account<'info, DriverProfile>>>,
+    #[account(
+        seeds = [POINTS_VAULT_SEED, driver_profile.referrer.as_ref()],
+        bump = referrer_points_vault.bump,
+    )]
+    pub referrer_points_vault: Option<Box<Account<'info, PointsVault>>>,
+    #[account(mut)]
+    pub referrer_vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CloseSessionArgs {
+    pub final_energy_wh: u64,
+    pub final_seconds: u64,
+    pub telemetry_hash: [u8; 32],
+    pub closed_at: i64,
+    pub end_soc_percent: Option<u8>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRecs<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = rec_mint,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        seeds = [OPERATOR_RECS_SEED, operator.key().as_ref()],
+        bump = operator_recs.bump,
+        has_one = operator @ DechargeError::Unauthorized,
+    )]
+    pub operator_recs: Account<'info, OperatorRecs>,
+    #[account(mut)]
+    pub rec_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = operator,
+        associated_token::mint = rec_mint,
+        associated_token::authority = operator,
+        associated_token::token_program = token_program,
+    )]
+    pub operator_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: GrantDriverDelegateArgs)]
+pub struct GrantDriverDelegate<'info> {
+    #[account(mut)]
+    pub driver: Signer<'info>,
+    #[account(
+        init,
+        payer = driver,
+        seeds = [DRIVER_DELEGATE_SEED, driver.key().as_ref(), args.delegate.as_ref()],
+        bump,
+        space = DriverDelegate::LEN,
+    )]
+    pub driver_delegate: Account<'info, DriverDelegate>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct GrantDriverDelegateArgs {
+    pub delegate: Pubkey,
+    pub scopes: u8,
+    pub max_spend_microunits: u64,
+    pub expires_at: i64,
+}
+
+#[derive(Accounts)]
+pub struct RevokeDriverDelegate<'info> {
+    #[account(mut)]
+    pub driver: Signer<'info>,
+    #[account(
+        mut,
+        close = driver,
+        seeds = [DRIVER_DELEGATE_SEED, driver.key().as_ref(), driver_delegate.delegate.as_ref()],
+        bump = driver_delegate.bump,
+        has_one = driver @ DechargeError::Unauthorized,
+    )]
+    pub driver_delegate: Account<'info, DriverDelegate>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawPoints<'info> {
+    #[account(mut)]
+    pub driver: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [DRIVER_PROFILE_SEED, driver.key().as_ref()],
+        bump = driver_profile.bump,
+        has_one = driver @ DechargeError::Unauthorized,
+    )]
+    pub driver_profile: Account<'info, DriverProfile>,
+    #[account(
+        seeds = [POINTS_VAULT_SEED, driver.key().as_ref()],
+        bump = points_vault.bump,
+    )]
+    pub points_vault: Account<'info, PointsVault>,
+    #[account(
+        mut,
+        address = points_vault.token_account,
+        token::mint = point_mint,
+        token::authority = points_vault,
+        token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = driver,
+        associated_token::mint = point_mint,
+        associated_token::authority = driver,
+        associated_token::token_program = token_program,
+    )]
+    pub driver_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub point_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct WithdrawPointsArgs {
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct TransferPoints<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = point_mint,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    #[account(
+        mut,
+        seeds = [DRIVER_PROFILE_SEED, sender.key().as_ref()],
+        bump = sender_profile.bump,
+        constraint = sender_profile.driver == sender.key() @ DechargeError::Unauthorized,
+    )]
+    pub sender_profile: Box<Account<'info, DriverProfile>>,
+    #[account(
+        seeds = [POINTS_VAULT_SEED, sender.key().as_ref()],
+        bump = sender_points_vault.bump,
+    )]
+    pub sender_points_vault: Box<Account<'info, PointsVault>>,
+    #[account(
+        mut,
+        address = sender_points_vault.token_account,
+    )]
+    pub sender_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [DRIVER_PROFILE_SEED, recipient_profile.driver.as_ref()],
+        bump = recipient_profile.bump,
+    )]
+    pub recipient_profile: Box<Account<'info, DriverProfile>>,
+    #[account(
+        init_if_needed,
+        payer = sender,
+        seeds = [POINTS_VAULT_SEED, recipient_profile.driver.as_ref()],
+        bump,
+        space = PointsVault::LEN,
+    )]
+    pub recipient_points_vault: Box<Account<'info, PointsVault>>,
+    #[account(
+        init_if_needed,
+        payer = sender,
+        associated_token::mint = point_mint,
+        associated_token::authority = recipient_points_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub point_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TransferPointsArgs {
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct PurchasePoints<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    #[account(mut)]
+    pub driver_profile: Account<'info, DriverProfile>,
+    #[account(
+        mut,
+        seeds = [POINTS_VAULT_SEED, driver_profile.driver.as_ref()],
+        bump = points_vault.bump,
+    )]
+    pub points_vault: Account<'info, PointsVault>,
+    #[account(
+        mut,
+        address = points_vault.token_account,
+        token::mint = point_mint,
+        token::authority = points_vault,
+        token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = point_mint,
+        associated_token::authority = buyer,
+        associated_token::token_program = token_program,
+    )]
+    pub buyer_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub point_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut, address = platform_config.payment_treasury)]
+    pub payment_treasury: SystemAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PurchasePointsArgs {
+    pub amount: u64,
+    pub price_lamports: u64,
+}
+
+#[derive(Accounts)]
+pub struct StakePoints<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = point_mint,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [POINT_STAKE_SEED, owner.key().as_ref()],
+        bump,
+        space = PointStake::LEN,
+    )]
+    pub stake: Box<Account<'info, PointStake>>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = point_mint,
+        associated_token::authority = stake,
+        associated_token::token_program = token_program,
+    )]
+    pub stake_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = point_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub point_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct StakePointsArgs {
+    pub amount: u64,
+    /// Lock measured from now; an existing later unlock time is kept.
+    pub lock_seconds: i64,
+}
+
+#[derive(Accounts)]
+pub struct UnstakePoints<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = point_mint,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        seeds = [POINT_STAKE_SEED, owner.key().as_ref()],
+        bump = stake.bump,
+        has_one = owner @ DechargeError::Unauthorized,
+    )]
+    pub stake: Account<'info, PointStake>,
+    #[account(
+        mut,
+        address = stake.token_account,
+    )]
+    pub stake_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = point_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub point_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        seeds = [POINT_STAKE_SEED, proposer.key().as_ref()],
+        bump = proposer_stake.bump,
+    )]
+    pub proposer_stake: Account<'info, PointStake>,
+    #[account(
+        init,
+        payer = proposer,
+        seeds = [PROPOSAL_SEED, platform_config.next_proposal.to_le_bytes().as_ref()],
+        bump,
+        space = Proposal::LEN,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateProposalArgs {
+    pub param: GovernanceParam,
+    pub value: u64,
+}
+
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        seeds = [POINT_STAKE_SEED, voter.key().as_ref()],
+        bump = voter_stake.bump,
+    )]
+    pub voter_stake: Account<'info, PointStake>,
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    #[account(
+        init,
+        payer = voter,
+        seeds = [VOTE_RECORD_SEED, proposal.key().as_ref(), voter.key().as_ref()],
+        bump,
+        space = VoteRecord::LEN,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CastVoteArgs {
+    pub support: bool,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    pub executor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+#[derive(Accounts)]
+pub struct ExpirePoints<'info> {
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = point_mint,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    #[account(
+        mut,
+        seeds = [DRIVER_PROFILE_SEED, driver_profile.driver.as_ref()],
+        bump = driver_profile.bump,
+    )]
+    pub driver_profile: Box<Account<'info, DriverProfile>>,
+    #[account(
+        seeds = [POINTS_VAULT_SEED, driver_profile.driver.as_ref()],
+        bump = points_vault.bump,
+    )]
+    pub points_vault: Box<Account<'info, PointsVault>>,
+    #[account(
+        mut,
+        address = points_vault.token_account,
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub point_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: CreateCatalogItemArgs)]
+pub struct CreateCatalogItem<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ DechargeError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
         init,
         payer = admin,
+        seeds = [CATALOG_ITEM_SEED, args.item_code.as_bytes()],
+        bump,
+        space = CatalogItem::LEN,
+    )]
+    pub catalog_item: Account<'info, CatalogItem>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateCatalogItemArgs {
+    pub item_code: String,
+    pub name: String,
+    pub points_cost: u64,
+    pub inventory: u32,
+    pub per_wallet_limit: u32,
+    pub starts_at: i64,
+    pub ends_at: i64,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCatalogItem<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ DechargeError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        constraint = catalog_item.platform == platform_config.key() @ DechargeError::Unauthorized,
+    )]
+    pub catalog_item: Account<'info, CatalogItem>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateCatalogItemArgs {
+    pub points_cost: Option<u64>,
+    pub inventory: Option<u32>,
+    pub per_wallet_limit: Option<u32>,
+    pub starts_at: Option<i64>,
+    pub ends_at: Option<i64>,
+    pub active: Option<bool>,
+}
+
+#[derive(Accounts)]
+pub struct RedeemItem<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    #[account(
         seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = point_mint,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    #[account(
+        mut,
+        constraint = catalog_item.platform == platform_config.key() @ DechargeError::Unauthorized,
+    )]
+    pub catalog_item: Box<Account<'info, CatalogItem>>,
+    #[account(
+        init_if_needed,
+        payer = holder,
+        seeds = [ITEM_REDEMPTION_SEED, catalog_item.key().as_ref(), holder.key().as_ref()],
         bump,
-        space = PlatformConfig::LEN,
+        space = ItemRedemption::LEN,
+    )]
+    pub item_redemption: Box<Account<'info, ItemRedemption>>,
+    #[account(
+        mut,
+        token::mint = point_mint,
+        token::authority = holder,
+        token::token_program = token_program,
+    )]
+    pub holder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub point_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeWorld<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ DechargeError::Unauthorized,
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     #[account(
         init,
         payer = admin,
-        seeds = [SESSION_COUNTER_SEED],
+        seeds = [WORLD_CONFIG_SEED],
         bump,
-        space = SessionCounter::LEN,
+        space = WorldConfig::LEN,
     )]
-    pub session_counter: Account<'info, SessionCounter>,
+    pub world_config: Account<'info, WorldConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InitializeWorldArgs {
+    pub max_plots: u32,
+    pub level_prices_lamports: [u64; MAX_PLOT_LEVEL as usize],
+    pub boost_prices_lamports: [u64; BOOST_KIND_COUNT],
+    pub boost_bps: [u16; BOOST_KIND_COUNT],
+    pub boost_duration_seconds: i64,
+    pub peak_start_hour: u8,
+    pub peak_end_hour: u8,
+    pub level_multiplier_bps: [u32; MAX_PLOT_LEVEL as usize],
+    pub owner_revenue_bps: u16,
+    pub plot_reward_points_per_slot_day: u64,
+    pub reward_halving_seconds: i64,
+    pub min_x: i32,
+    pub min_y: i32,
+    pub max_x: i32,
+    pub max_y: i32,
+}
+
+#[derive(Accounts)]
+pub struct UpdateWorldConfig<'info> {
+    pub admin: Signer<'info>,
     #[account(
-        init,
-        payer = admin,
-        mint::decimals = DEFAULT_POINTS_DECIMALS,
-        mint::authority = platform_config,
-        mint::freeze_authority = platform_config,
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ DechargeError::Unauthorized,
     )]
-    pub point_mint: Account<'info, Mint>,
-    /// Treasury account where SOL/USDC payments are routed
-    #[account(mut)]
-    pub payment_treasury: SystemAccount<'info>,
-    /// Treasury for world rewards
-    #[account(mut)]
-    pub world_treasury: SystemAccount<'info>,
-    pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        seeds = [WORLD_CONFIG_SEED],
+        bump = world_config.bump,
+    )]
+    pub world_config: Account<'info, WorldConfig>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct InitializePlatformArgs {
-    pub oracle: Pubkey,
-    pub point_rate_microunits: u64,
+pub struct UpdateWorldConfigArgs {
+    pub max_plots: Option<u32>,
+    pub level_prices_lamports: Option<[u64; MAX_PLOT_LEVEL as usize]>,
+    pub boost_prices_lamports: Option<[u64; BOOST_KIND_COUNT]>,
+    pub boost_bps: Option<[u16; BOOST_KIND_COUNT]>,
+    pub boost_duration_seconds: Option<i64>,
+    /// Start and end UTC hour of the Community Boost window.
+    pub peak_hours: Option<(u8, u8)>,
+    pub owner_revenue_bps: Option<u16>,
 }
 
 #[derive(Accounts)]
-#[instruction(args: RegisterStationArgs)]
-pub struct RegisterStation<'info> {
+#[instruction(args: CreateRegionArgs)]
+pub struct CreateRegion<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
     #[account(
-        mut,
         seeds = [PLATFORM_SEED],
         bump = platform_config.bump,
         has_one = admin @ DechargeError::Unauthorized,
     )]
     pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        seeds = [WORLD_CONFIG_SEED],
+        bump = world_config.bump,
+    )]
+    pub world_config: Account<'info, WorldConfig>,
     #[account(
         init,
         payer = admin,
-        seeds = [
-            STATION_SEED,
-            args.station_code.as_bytes(),
-        ],
+        seeds = [REGION_SEED, args.key.as_bytes()],
         bump,
-        space = ChargingStation::LEN,
+        space = Region::LEN,
     )]
-    pub station: Account<'info, ChargingStation>,
+    pub region: Account<'info, Region>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct RegisterStationArgs {
-    pub station_code: String,
-    pub name: String,
-    pub city: String,
-    pub location_lat_micro: i32,
-    pub location_lon_micro: i32,
-    pub max_kw: u32,
-    pub pricing_energy_microunits: u64,
-    pub pricing_time_microunits: u64,
-    pub uri: String,
+pub struct CreateRegionArgs {
+    pub key: String,
+    pub min_x: i32,
+    pub min_y: i32,
+    pub max_x: i32,
+    pub max_y: i32,
+    pub max_plots: u32,
+    pub base_reward_points: u64,
 }
 
 #[derive(Accounts)]
-pub struct StartSession<'info> {
+#[instruction(x: i32, y: i32)]
+pub struct DefinePlot<'info> {
     #[account(mut)]
-    pub oracle: Signer<'info>,
+    pub admin: Signer<'info>,
     #[account(
-        mut,
         seeds = [PLATFORM_SEED],
         bump = platform_config.bump,
-        has_one = oracle @ DechargeError::Unauthorized,
+        has_one = admin @ DechargeError::Unauthorized,
     )]
     pub platform_config: Account<'info, PlatformConfig>,
-    #[account(mut)]
-    pub session_counter: Account<'info, SessionCounter>,
-    #[account(mut)]
-    pub station: Account<'info, ChargingStation>,
-    /// CHECK: validated via CPI or off-chain oracle signature
-    pub driver: AccountInfo<'info>,
     #[account(
-        init_if_needed,
-        payer = oracle,
-        seeds = [DRIVER_PROFILE_SEED, driver.key().as_ref()],
-        bump,
-        space = DriverProfile::LEN,
+        mut,
+        seeds = [WORLD_CONFIG_SEED],
+        bump = world_config.bump,
     )]
-    pub driver_profile: Account<'info, DriverProfile>,
+    pub world_config: Account<'info, WorldConfig>,
+    #[account(mut)]
+    pub region: Account<'info, Region>,
     #[account(
         init,
-        payer = oracle,
-        seeds = [SESSION_SEED, session_counter.next_session.to_le_bytes().as_ref()],
+        payer = admin,
+        seeds = [
+            WORLD_PLOT_SEED,
+            x.to_le_bytes().as_ref(),
+            y.to_le_bytes().as_ref(),
+        ],
         bump,
-        space = ChargingSession::LEN,
+        space = WorldPlot::LEN,
     )]
-    pub session: Account<'info, ChargingSession>,
+    pub plot: Account<'info, WorldPlot>,
     pub system_program: Program<'info, System>,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct StartSessionArgs {
-    pub session_hash: [u8; 32],
-    pub timestamp: i64,
+#[derive(Accounts)]
+pub struct ClaimWorldPlot<'info> {
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            WORLD_PLOT_SEED,
+            plot.x.to_le_bytes().as_ref(),
+            plot.y.to_le_bytes().as_ref(),
+        ],
+        bump = plot.bump,
+        has_one = region,
+        constraint = plot.owner == Pubkey::default() @ DechargeError::PlotOccupied,
+    )]
+    pub plot: Account<'info, WorldPlot>,
+    #[account(mut)]
+    pub region: Account<'info, Region>,
+    #[account(
+        mut,
+        seeds = [WORLD_CONFIG_SEED],
+        bump = world_config.bump,
+    )]
+    pub world_config: Account<'info, WorldConfig>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut, address = platform_config.world_treasury)]
+    pub world_treasury: SystemAccount<'info>,
+    /// Only needed when `plot_claim_fee_points` is set.
+    #[account(mut)]
+    pub claimant_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = platform_config.point_mint)]
+    pub point_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct RecordTelemetry<'info> {
+pub struct UpgradePlot<'info> {
     #[account(mut)]
-    pub oracle: Signer<'info>,
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        has_one = owner @ DechargeError::Unauthorized,
+        has_one = region,
+    )]
+    pub plot: Account<'info, WorldPlot>,
+    pub region: Box<Account<'info, Region>>,
+    #[account(
+        seeds = [PLOT_BOOST_SEED, plot.key().as_ref(), &[BoostKind::GridSync as u8]],
+        bump = grid_sync_boost.bump,
+    )]
+    pub grid_sync_boost: Option<Box<Account<'info, PlotBoost>>>,
+    #[account(
+        seeds = [WORLD_CONFIG_SEED],
+        bump = world_config.bump,
+    )]
+    pub world_config: Account<'info, WorldConfig>,
     #[account(
         seeds = [PLATFORM_SEED],
         bump = platform_config.bump,
-        has_one = oracle @ DechargeError::Unauthorized,
     )]
     pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut, address = platform_config.world_treasury)]
+    pub world_treasury: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: PurchasePlotBoostArgs)]
+pub struct PurchasePlotBoost<'info> {
     #[account(mut)]
-    pub session: Account<'info, ChargingSession>,
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        has_one = owner @ DechargeError::Unauthorized,
+        has_one = region,
+    )]
+    pub plot: Account<'info, WorldPlot>,
+    pub region: Box<Account<'info, Region>>,
+    /// Settles passive rewards before the change; ignored when buying Grid Sync itself
+    #[account(
+        seeds = [PLOT_BOOST_SEED, plot.key().as_ref(), &[BoostKind::GridSync as u8]],
+        bump = grid_sync_boost.bump,
+    )]
+    pub grid_sync_boost: Option<Box<Account<'info, PlotBoost>>>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [PLOT_BOOST_SEED, plot.key().as_ref(), &[args.kind as u8]],
+        bump,
+        space = PlotBoost::LEN,
+    )]
+    pub boost: Account<'info, PlotBoost>,
+    #[account(
+        seeds = [WORLD_CONFIG_SEED],
+        bump = world_config.bump,
+    )]
+    pub world_config: Account<'info, WorldConfig>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut, address = platform_config.world_treasury)]
+    pub world_treasury: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct TelemetryArgs {
-    pub energy_delta_wh: u64,
-    pub seconds_delta: u64,
-    pub telemetry_hash: [u8; 32],
+pub struct PurchasePlotBoostArgs {
+    pub kind: BoostKind,
 }
 
 #[derive(Accounts)]
-pub struct CloseSession<'info> {
+#[instruction(args: VirtualSessionArgs)]
+pub struct RecordVirtualSession<'info> {
     #[account(mut)]
     pub oracle: Signer<'info>,
     #[account(
         seeds = [PLATFORM_SEED],
         bump = platform_config.bump,
         has_one = oracle @ DechargeError::Unauthorized,
+        has_one = point_mint,
     )]
     pub platform_config: Box<Account<'info, PlatformConfig>>,
-    #[account(mut)]
-    pub session: Box<Account<'info, ChargingSession>>,
-    #[account(mut)]
-    pub driver_profile: Box<Account<'info, DriverProfile>>,
-    #[account(mut)]
-    pub station: Box<Account<'info, ChargingStation>>,
     #[account(
-        init_if_needed,
+        seeds = [WORLD_CONFIG_SEED],
+        bump = world_config.bump,
+    )]
+    pub world_config: Box<Account<'info, WorldConfig>>,
+    #[account(
+        mut,
+        constraint = plot.owner != Pubkey::default() @ DechargeError::PlotUnclaimed,
+    )]
+    pub plot: Box<Account<'info, WorldPlot>>,
+    #[account(
+        init,
         payer = oracle,
-        seeds = [POINTS_VAULT_SEED, driver_profile.driver.as_ref()],
+        seeds = [VIRTUAL_SESSION_SEED, plot.key().as_ref(), args.session_id.as_ref()],
         bump,
-        space = PointsVault::LEN,
+        space = VirtualSessionRecord::LEN,
     )]
-    pub points_vault: Box<Account<'info, PointsVault>>,
+    pub virtual_session: Box<Account<'info, VirtualSessionRecord>>,
+    #[account(
+        seeds = [PLOT_BOOST_SEED, plot.key().as_ref(), &[BoostKind::SolarArray as u8]],
+        bump = solar_boost.bump,
+    )]
+    pub solar_boost: Option<Box<Account<'info, PlotBoost>>>,
+    #[account(
+        seeds = [PLOT_BOOST_SEED, plot.key().as_ref(), &[BoostKind::CommunityBoost as u8]],
+        bump = community_boost.bump,
+    )]
+    pub community_boost: Option<Box<Account<'info, PlotBoost>>>,
+    /// CHECK: receives the revenue share; must be the plot owner
+    #[account(address = plot.owner)]
+    pub plot_owner: UncheckedAccount<'info>,
     #[account(
         init_if_needed,
         payer = oracle,
         associated_token::mint = point_mint,
-        associated_token::authority = points_vault,
+        associated_token::authority = plot_owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub point_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// Required while an emission budget is set
+    #[account(
+        mut,
+        seeds = [POINT_EMISSIONS_SEED],
+        bump = point_emissions.bump,
     )]
-    pub vault_token_account: Box<Account<'info, TokenAccount>>,
-    pub point_mint: Box<Account<'info, Mint>>,
-    pub token_program: Program<'info, Token>,
+    pub point_emissions: Option<Box<Account<'info, PointEmissions>>>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct CloseSessionArgs {
-    pub final_energy_wh: u64,
-    pub final_seconds: u64,
-    pub telemetry_hash: [u8; 32],
-    pub closed_at: i64,
+pub struct VirtualSessionArgs {
+    /// Oracle-assigned session identifier; each one can be recorded once per plot.
+    pub session_id: [u8; 32],
+    pub energy_wh: u64,
 }
 
 #[derive(Accounts)]
-pub struct PurchasePoints<'info> {
+pub struct ClaimPlotRewards<'info> {
     #[account(mut)]
-    pub buyer: Signer<'info>,
-    #[account(mut)]
-    pub driver_profile: Account<'info, DriverProfile>,
+    pub owner: Signer<'info>,
     #[account(
-        mut,
-        seeds = [POINTS_VAULT_SEED, driver_profile.driver.as_ref()],
-        bump = points_vault.bump,
+        seeds = [PLATFORM_SEED],
+        bump = platform_config.bump,
+        has_one = point_mint,
     )]
-    pub points_vault: Account<'info, PointsVault>,
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    #[account(
+        seeds = [WORLD_CONFIG_SEED],
+        bump = world_config.bump,
+    )]
+    pub world_config: Box<Account<'info, WorldConfig>>,
     #[account(
         mut,
-        address = points_vault.token_account,
-        token::mint = point_mint,
-        token::authority = points_vault,
+        has_one = owner @ DechargeError::Unauthorized,
+        has_one = region,
+    )]
+    pub plot: Box<Account<'info, WorldPlot>>,
+    pub region: Box<Account<'info, Region>>,
+    #[account(
+        seeds = [PLOT_BOOST_SEED, plot.key().as_ref(), &[BoostKind::GridSync as u8]],
+        bump = grid_sync_boost.bump,
     )]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    pub grid_sync_boost: Option<Box<Account<'info, PlotBoost>>>,
     #[account(
         init_if_needed,
-        payer = buyer,
+        payer = owner,
         associated_token::mint = point_mint,
-        associated_token::authority = buyer,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
     )]
-    pub buyer_token_account: Account<'info, TokenAccount>,
-    pub point_mint: Account<'info, Mint>,
+    pub owner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub point_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// Required while an emission budget is set
     #[account(
         mut,
-        seeds = [PLATFORM_SEED],
-        bump = platform_config.bump,
+        seeds = [POINT_EMISSIONS_SEED],
+        bump = point_emissions.bump,
     )]
-    pub platform_config: Account<'info, PlatformConfig>,
-    #[account(mut, address = platform_config.payment_treasury)]
-    pub payment_treasury: SystemAccount<'info>,
-    pub token_program: Program<'info, Token>,
+    pub point_emissions: Option<Box<Account<'info, PointEmissions>>>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct PurchasePointsArgs {
-    pub amount: u64,
-    pub price_lamports: u64,
-}
-
-#[derive(Accounts)]
-#[instruction(region_key: [u8; 64])]
-pub struct ClaimWorldPlot<'info> {
-    #[account(mut)]
-    pub claimant: Signer<'info>,
-    #[account(
-        init,
-        payer = claimant,
-        seeds = [WORLD_PLOT_SEED, region_key.as_ref()],
-        bump,
-        space = WorldPlot::LEN,
-    )]
-    pub plot: Account<'info, WorldPlot>,
-    pub system_program: Program<'info, System>,
-}
@@ -23,6 +23,32 @@ pub mod decharge {
         instructions::initialize_platform(ctx, args)
     }
 
+    pub fn initialize_platform_with_metadata(
+        ctx: Context<InitializePlatformWithMetadata>,
+        args: InitializePlatformArgs,
+        metadata: PointMetadataArgs,
+    ) -> Result<()> {
+        instructions::initialize_platform_with_metadata(ctx, args, metadata)
+    }
+
+    pub fn update_point_metadata(
+        ctx: Context<UpdatePointMetadata>,
+        metadata: PointMetadataArgs,
+    ) -> Result<()> {
+        instructions::update_point_metadata(ctx, metadata)
+    }
+
+    pub fn initialize_rec_mint(ctx: Context<InitializeRecMint>) -> Result<()> {
+        instructions::initialize_rec_mint(ctx)
+    }
+
+    pub fn update_platform_config(
+        ctx: Context<UpdatePlatformConfig>,
+        args: UpdatePlatformConfigArgs,
+    ) -> Result<()> {
+        instructions::update_platform_config(ctx, args)
+    }
+
     pub fn register_station(
         ctx: Context<RegisterStation>,
         args: RegisterStationArgs,
@@ -30,6 +56,20 @@ pub mod decharge {
         instructions::register_station(ctx, args)
     }
 
+    pub fn update_station_carbon_profile(
+        ctx: Context<UpdateStationCarbonProfile>,
+        args: StationCarbonProfileArgs,
+    ) -> Result<()> {
+        instructions::update_station_carbon_profile(ctx, args)
+    }
+
+    pub fn register_vehicle(
+        ctx: Context<RegisterVehicle>,
+        args: RegisterVehicleArgs,
+    ) -> Result<()> {
+        instructions::register_vehicle(ctx, args)
+    }
+
     pub fn start_session(
         ctx: Context<StartSession>,
         args: StartSessionArgs,
@@ -37,6 +77,10 @@ pub mod decharge {
         instructions::start_session(ctx, args)
     }
 
+    pub fn request_stop_session(ctx: Context<RequestStopSession>) -> Result<()> {
+        instructions::request_stop_session(ctx)
+    }
+
     pub fn record_telemetry(
         ctx: Context<RecordTelemetry>,
         args: TelemetryArgs,
@@ -44,6 +88,17 @@ pub mod decharge {
         instructions::record_telemetry(ctx, args)
     }
 
+    pub fn initialize_telemetry_log(ctx: Context<InitializeTelemetryLog>) -> Result<()> {
+        instructions::initialize_telemetry_log(ctx)
+    }
+
+    pub fn record_telemetry_batch(
+        ctx: Context<RecordTelemetryBatch>,
+        args: TelemetryBatchArgs,
+    ) -> Result<()> {
+        instructions::record_telemetry_batch(ctx, args)
+    }
+
     pub fn close_session(
         ctx: Context<CloseSession>,
         args: CloseSessionArgs,
@@ -51,6 +106,35 @@ pub mod decharge {
         instructions::close_session(ctx, args)
     }
 
+    pub fn claim_recs(ctx: Context<ClaimRecs>) -> Result<()> {
+        instructions::claim_recs(ctx)
+    }
+
+    pub fn grant_driver_delegate(
+        ctx: Context<GrantDriverDelegate>,
+        args: GrantDriverDelegateArgs,
+    ) -> Result<()> {
+        instructions::grant_driver_delegate(ctx, args)
+    }
+
+    pub fn revoke_driver_delegate(ctx: Context<RevokeDriverDelegate>) -> Result<()> {
+        instructions::revoke_driver_delegate(ctx)
+    }
+
+    pub fn withdraw_points(
+        ctx: Context<WithdrawPoints>,
+        args: WithdrawPointsArgs,
+    ) -> Result<()> {
+        instructions::withdraw_points(ctx, args)
+    }
+
+    pub fn transfer_points(
+        ctx: Context<TransferPoints>,
+        args: TransferPointsArgs,
+    ) -> Result<()> {
+        instructions::transfer_points(ctx, args)
+    }
+
     pub fn purchase_points(
         ctx: Context<PurchasePoints>,
         args: PurchasePointsArgs,
@@ -58,10 +142,96 @@ pub mod decharge {
         instructions::purchase_points(ctx, args)
     }
 
-    pub fn claim_world_plot(
-        ctx: Context<ClaimWorldPlot>,
-        region_key: [u8; 64],
+    pub fn stake_points(ctx: Context<StakePoints>, args: StakePointsArgs) -> Result<()> {
+        instructions::stake_points(ctx, args)
+    }
+
+    pub fn unstake_points(ctx: Context<UnstakePoints>) -> Result<()> {
+        instructions::unstake_points(ctx)
+    }
+
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        args: CreateProposalArgs,
+    ) -> Result<()> {
+        instructions::create_proposal(ctx, args)
+    }
+
+    pub fn cast_vote(ctx: Context<CastVote>, args: CastVoteArgs) -> Result<()> {
+        instructions::cast_vote(ctx, args)
+    }
+
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        instructions::execute_proposal(ctx)
+    }
+
+    pub fn expire_points(ctx: Context<ExpirePoints>) -> Result<()> {
+        instructions::expire_points(ctx)
+    }
+
+    pub fn create_catalog_item(
+        ctx: Context<CreateCatalogItem>,
+        args: CreateCatalogItemArgs,
+    ) -> Result<()> {
+        instructions::create_catalog_item(ctx, args)
+    }
+
+    pub fn update_catalog_item(
+        ctx: Context<UpdateCatalogItem>,
+        args: UpdateCatalogItemArgs,
+    ) -> Result<()> {
+        instructions::update_catalog_item(ctx, args)
+    }
+
+    pub fn redeem_item(ctx: Context<RedeemItem>) -> Result<()> {
+        instructions::redeem_item(ctx)
+    }
+
+    pub fn initialize_world(
+        ctx: Context<InitializeWorld>,
+        args: InitializeWorldArgs,
     ) -> Result<()> {
-        instructions::claim_world_plot(ctx, region_key)
+        instructions::initialize_world(ctx, args)
+    }
+
+    pub fn update_world_config(
+        ctx: Context<UpdateWorldConfig>,
+        args: UpdateWorldConfigArgs,
+    ) -> Result<()> {
+        instructions::update_world_config(ctx, args)
+    }
+
+    pub fn create_region(ctx: Context<CreateRegion>, args: CreateRegionArgs) -> Result<()> {
+        instructions::create_region(ctx, args)
+    }
+
+    pub fn define_plot(ctx: Context<DefinePlot>, x: i32, y: i32) -> Result<()> {
+        instructions::define_plot(ctx, x, y)
+    }
+
+    pub fn claim_world_plot(ctx: Context<ClaimWorldPlot>) -> Result<()> {
+        instructions::claim_world_plot(ctx)
+    }
+
+    pub fn upgrade_plot(ctx: Context<UpgradePlot>, target_level: u8) -> Result<()> {
+        instructions::upgrade_plot(ctx, target_level)
+    }
+
+    pub fn purchase_plot_boost(
+        ctx: Context<PurchasePlotBoost>,
+        args: PurchasePlotBoostArgs,
+    ) -> Result<()> {
+        instructions::purchase_plot_boost(ctx, args)
+    }
+
+    pub fn record_virtual_session(
+        ctx: Context<RecordVirtualSession>,
+        args: VirtualSessionArgs,
+    ) -> Result<()> {
+        instructions::record_virtual_session(ctx, args)
+    }
+
+    pub fn claim_plot_rewards(ctx: Context<ClaimPlotRewards>) -> Result<()> {
+        instructions::claim_plot_rewards(ctx)
     }
 }
@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{MAX_CITY_LEN, MAX_NAME_LEN, MAX_URI_LEN};
+use crate::constants::{
+    BOOST_KIND_COUNT, BPS_DENOMINATOR, MAX_CITY_LEN, MAX_LOYALTY_TIERS, MAX_NAME_LEN,
+    MAX_PLOT_LEVEL, MAX_POINT_BUCKETS, MAX_STAKE_LOCK_SECONDS, MAX_URI_LEN, MICROS_PER_POINT,
+    SECONDS_PER_DAY, SECONDS_PER_HOUR, TELEMETRY_LOG_CAPACITY, WH_PER_KWH,
+};
+use crate::errors::DechargeError;
 
 #[account]
 pub struct PlatformConfig {
@@ -11,19 +16,158 @@ pub struct PlatformConfig {
     pub point_rate_microunits: u64,
     pub payment_treasury: Pubkey,
     pub world_treasury: Pubkey,
+    /// Tailpipe emissions of a comparable combustion vehicle per kWh delivered.
+    pub ice_baseline_g_per_kwh: u32,
+    /// Renewable energy certificate mint, default until `initialize_rec_mint` runs.
+    pub rec_mint: Pubkey,
+    /// Length of a points earning epoch; zero disables expiry.
+    pub points_epoch_seconds: i64,
+    /// Number of epochs earned points stay spendable.
+    pub points_expiry_epochs: u32,
+    /// Ascending loyalty tiers; a driver holds the highest tier they qualify for.
+    pub loyalty_tiers: [LoyaltyTier; MAX_LOYALTY_TIERS],
+    /// Trailing window for tier qualification; zero uses lifetime totals.
+    pub tier_window_seconds: i64,
+    /// Energy a referred driver must charge before referral bonuses pay out; zero disables.
+    pub referral_threshold_wh: u64,
+    pub referrer_bonus_points: u64,
+    pub referee_bonus_points: u64,
+    pub max_referrals_per_referrer: u32,
+    /// Points mintable per points epoch; zero means unlimited.
+    pub epoch_emission_budget: u64,
+    /// Points mintable over the platform lifetime, counted from when a budget is first set;
+    /// zero means unlimited.
+    pub lifetime_emission_budget: u64,
+    /// Share of a budget after which `EmissionBudgetWarning` is emitted.
+    pub emission_warning_bps: u16,
+    /// Points a driver may send to other drivers per day; zero means unlimited.
+    pub daily_transfer_limit: u64,
+    /// Voting power a proposal needs in favour to pass; zero disables governance.
+    pub governance_quorum_power: u64,
+    pub governance_voting_seconds: i64,
+    /// Index of the next governance proposal; seeds the proposal PDA.
+    pub next_proposal: u64,
+    /// Fees charged by `claim_world_plot`, paid into `world_treasury`.
+    pub plot_claim_fee_lamports: u64,
+    pub plot_claim_fee_points: u64,
+    /// When points epochs were enabled; epoch one starts here.
+    pub points_epoch_start: i64,
 }
 
 impl PlatformConfig {
-    pub const LEN: usize = 8 + (32 * 5) + 1 + 8;
+    pub const LEN: usize = 8 + (32 * 5) + 1 + 8 + 4 + 32 + 8 + 4
+        + (LoyaltyTier::LEN * MAX_LOYALTY_TIERS) + 8 + (8 * 3) + 4 + (8 * 2) + 2 + 8 + (8 * 3)
+        + (8 * 2) + 8;
+
+    pub fn tier_window(&self, now: i64) -> u64 {
+        if self.tier_window_seconds <= 0 {
+            return 0;
+        }
+        u64::try_from(now / self.tier_window_seconds).unwrap_or_default()
+    }
+
+    /// Returns the tier (zero for none) for the given trailing activity.
+    pub fn loyalty_tier(&self, energy_wh: u64, sessions: u64) -> u8 {
+        self.loyalty_tiers
+            .iter()
+            .enumerate()
+            .filter(|(_, tier)| tier.qualifies(energy_wh, sessions))
+            .map(|(index, _)| index as u8 + 1)
+            .max()
+            .unwrap_or_default()
+    }
+
+    pub fn tier_multiplier_bps(&self, tier: u8) -> u64 {
+        match tier {
+            0 => BPS_DENOMINATOR,
+            tier => u64::from(self.loyalty_tiers[usize::from(tier) - 1].multiplier_bps),
+        }
+    }
+
+    pub fn has_emission_budget(&self) -> bool {
+        self.epoch_emission_budget > 0 || self.lifetime_emission_budget > 0
+    }
+
+    /// Epoch zero holds points earned while epochs were disabled.
+    pub fn points_epoch(&self, now: i64) -> u64 {
+        if self.points_epoch_seconds <= 0 {
+            return 0;
+        }
+        let elapsed = now.saturating_sub(self.points_epoch_start).max(0);
+        u64::try_from(elapsed / self.points_epoch_seconds)
+            .unwrap_or_default()
+            .saturating_add(1)
+    }
+
+    /// Buckets earned before the returned epoch have expired, if expiry is enabled.
+    /// Epoch zero points count as earned in epoch one, so enabling expiry never
+    /// expires them at once.
+    pub fn points_expiry_cutoff(&self, now: i64) -> Option<u64> {
+        if self.points_epoch_seconds <= 0 || self.points_expiry_epochs == 0 {
+            return None;
+        }
+        let cutoff = self
+            .points_epoch(now)
+            .saturating_sub(u64::from(self.points_expiry_epochs))
+            .saturating_add(1);
+        (cutoff > 1).then_some(cutoff)
+    }
 }
 
+/// Points minted against the budgets in `PlatformConfig`.
 #[account]
-pub struct SessionCounter {
-    pub next_session: u64,
+pub struct PointEmissions {
+    pub epoch: u64,
+    pub epoch_minted: u64,
+    pub lifetime_minted: u64,
+    pub bump: u8,
 }
 
-impl SessionCounter {
-    pub const LEN: usize = 8 + 8;
+impl PointEmissions {
+    pub const LEN: usize = 8 + (8 * 3) + 1;
+
+    pub fn roll_epoch(&mut self, epoch: u64) {
+        if epoch != self.epoch {
+            self.epoch = epoch;
+            self.epoch_minted = 0;
+        }
+    }
+
+    pub fn remaining(&self, config: &PlatformConfig) -> u64 {
+        let epoch_remaining = match config.epoch_emission_budget {
+            0 => u64::MAX,
+            budget => budget.saturating_sub(self.epoch_minted),
+        };
+        let lifetime_remaining = match config.lifetime_emission_budget {
+            0 => u64::MAX,
+            budget => budget.saturating_sub(self.lifetime_minted),
+        };
+        epoch_remaining.min(lifetime_remaining)
+    }
+
+    /// Records as much of `requested` as the budgets allow and returns the granted amount.
+    pub fn allocate(&mut self, config: &PlatformConfig, requested: u64) -> Result<u64> {
+        let granted = requested.min(self.remaining(config));
+        self.epoch_minted = self
+            .epoch_minted
+            .checked_add(granted)
+            .ok_or(DechargeError::NumericalOverflow)?;
+        self.lifetime_minted = self
+            .lifetime_minted
+            .checked_add(granted)
+            .ok_or(DechargeError::NumericalOverflow)?;
+        Ok(granted)
+    }
+
+    pub fn near_cap(&self, config: &PlatformConfig) -> bool {
+        let past_warning = |minted: u64, budget: u64| {
+            budget > 0
+                && u128::from(minted) * u128::from(BPS_DENOMINATOR)
+                    >= u128::from(budget) * u128::from(config.emission_warning_bps)
+        };
+        past_warning(self.epoch_minted, config.epoch_emission_budget)
+            || past_warning(self.lifetime_minted, config.lifetime_emission_budget)
+    }
 }
 
 #[account]
@@ -40,10 +184,55 @@ pub struct ChargingStation {
     pub pricing_time_microunits: u64,
     pub uri: [u8; MAX_URI_LEN],
     pub bump: u8,
+    /// Index of the next session opened at this station; seeds the session PDA.
+    pub next_session: u64,
+    pub grid_carbon_g_per_kwh: u32,
+    pub renewable_fraction_bps: u16,
+    pub total_energy_wh: u64,
+    pub total_co2_avoided_g: u64,
+    /// `CONNECTOR_TYPE_*` bitflags offered here; zero accepts any vehicle.
+    pub connector_types: u8,
 }
 
 impl ChargingStation {
-    pub const LEN: usize = 8 + 32 + 32 + MAX_CITY_LEN + MAX_NAME_LEN + MAX_URI_LEN + 4 + 4 + 4 + 1 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + MAX_CITY_LEN + MAX_NAME_LEN + MAX_URI_LEN + 4 + 4 + 4 + 1 + 8 + 8 + 1 + 8
+        + 4 + 2 + (8 * 2) + 1;
+
+    pub fn supports_connectors(&self, connector_types: u8) -> bool {
+        self.connector_types == 0 || self.connector_types & connector_types != 0
+    }
+
+    /// Emissions avoided by delivering `energy_wh` here instead of burning fuel.
+    pub fn co2_avoided_g(&self, energy_wh: u64, ice_baseline_g_per_kwh: u32) -> Result<u64> {
+        let energy_wh = u128::from(energy_wh);
+        let baseline_g = energy_wh * u128::from(ice_baseline_g_per_kwh);
+        let grid_share_bps = u128::from(BPS_DENOMINATOR)
+            .checked_sub(u128::from(self.renewable_fraction_bps))
+            .ok_or(DechargeError::InvalidBasisPoints)?;
+        let grid_g = energy_wh * u128::from(self.grid_carbon_g_per_kwh) * grid_share_bps
+            / u128::from(BPS_DENOMINATOR);
+        let avoided_g = baseline_g.saturating_sub(grid_g) / u128::from(WH_PER_KWH);
+        u64::try_from(avoided_g).map_err(|_| error!(DechargeError::NumericalOverflow))
+    }
+
+    pub fn renewable_wh(&self, energy_wh: u64) -> Result<u64> {
+        let renewable_wh = u128::from(energy_wh) * u128::from(self.renewable_fraction_bps)
+            / u128::from(BPS_DENOMINATOR);
+        u64::try_from(renewable_wh).map_err(|_| error!(DechargeError::NumericalOverflow))
+    }
+}
+
+/// Renewable energy delivered at an operator's stations that has not yet been certified.
+#[account]
+pub struct OperatorRecs {
+    pub operator: Pubkey,
+    pub accrued_renewable_wh: u64,
+    pub total_recs_claimed: u64,
+    pub bump: u8,
+}
+
+impl OperatorRecs {
+    pub const LEN: usize = 8 + 32 + (8 * 2) + 1;
 }
 
 #[account]
@@ -54,10 +243,255 @@ pub struct DriverProfile {
     pub total_points_earned: u64,
     pub outstanding_points: u64,
     pub bump: u8,
+    pub total_co2_avoided_g: u64,
+    /// Outstanding points grouped by the epoch they were earned in.
+    pub point_buckets: [PointBucket; MAX_POINT_BUCKETS],
+    pub tier: u8,
+    pub tier_window: u64,
+    pub current_window_energy_wh: u64,
+    pub current_window_sessions: u64,
+    pub previous_window_energy_wh: u64,
+    pub previous_window_sessions: u64,
+    /// Driver who referred this one, or default if none.
+    pub referrer: Pubkey,
+    pub referral_rewarded: bool,
+    pub referrals_rewarded: u32,
+    /// Fraction of a point truncated by earlier mints, in point micro-units times basis points.
+    pub point_remainder: u64,
+    /// Points earned but held back by the emission budget, paid out on later sessions.
+    pub deferred_points: u64,
+    pub transfer_day: u64,
+    pub transferred_today: u64,
 }
 
 impl DriverProfile {
-    pub const LEN: usize = 8 + 32 + (8 * 4) + 1;
+    pub const LEN: usize = 8 + 32 + (8 * 4) + 1 + 8 + (PointBucket::LEN * MAX_POINT_BUCKETS)
+        + 1 + (8 * 5) + 32 + 1 + 4 + 8 + 8 + (8 * 2);
+
+    /// Adds `amount` to today's sent total, enforcing `daily_limit` when non-zero.
+    pub fn record_transfer(&mut self, day: u64, amount: u64, daily_limit: u64) -> Result<()> {
+        if day != self.transfer_day {
+            self.transfer_day = day;
+            self.transferred_today = 0;
+        }
+        self.transferred_today = self
+            .transferred_today
+            .checked_add(amount)
+            .ok_or(DechargeError::NumericalOverflow)?;
+        require!(
+            daily_limit == 0 || self.transferred_today <= daily_limit,
+            DechargeError::DailyTransferLimitExceeded
+        );
+        Ok(())
+    }
+
+    /// Moves points to `recipient` oldest first, keeping the epochs they were earned in.
+    /// Points not tracked by any bucket are credited to `fallback_epoch`.
+    pub fn move_points(
+        &mut self,
+        recipient: &mut DriverProfile,
+        amount: u64,
+        fallback_epoch: u64,
+    ) -> Result<()> {
+        self.outstanding_points = self
+            .outstanding_points
+            .checked_sub(amount)
+            .ok_or(DechargeError::InsufficientPoints)?;
+
+        let mut remaining = amount;
+        while remaining > 0 {
+            let Some(slot) = self.oldest_bucket() else {
+                break;
+            };
+            let bucket = &mut self.point_buckets[slot];
+            let taken = remaining.min(bucket.amount);
+            bucket.amount -= taken;
+            remaining -= taken;
+            recipient.credit_points(bucket.epoch, taken)?;
+        }
+        recipient.credit_points(fallback_epoch, remaining)
+    }
+
+    /// Converts scaled points into whole points, carrying the fraction into the next call.
+    pub fn carry_points(&mut self, scaled_points: u128) -> Result<u64> {
+        let unit = u128::from(MICROS_PER_POINT) * u128::from(BPS_DENOMINATOR);
+        let total = scaled_points
+            .checked_add(u128::from(self.point_remainder))
+            .ok_or(DechargeError::NumericalOverflow)?;
+        // The remainder is always below `unit`, which fits in a u64.
+        self.point_remainder = (total % unit) as u64;
+        u64::try_from(total / unit).map_err(|_| error!(DechargeError::NumericalOverflow))
+    }
+
+    pub fn referral_due(&self, threshold_wh: u64) -> bool {
+        self.referrer != Pubkey::default()
+            && !self.referral_rewarded
+            && threshold_wh > 0
+            && self.total_energy_wh >= threshold_wh
+    }
+
+    /// Adds a session to the trailing activity window, rolling it forward if needed.
+    pub fn record_tier_activity(&mut self, window: u64, energy_wh: u64) -> Result<()> {
+        if window != self.tier_window {
+            if window == self.tier_window.saturating_add(1) {
+                self.previous_window_energy_wh = self.current_window_energy_wh;
+                self.previous_window_sessions = self.current_window_sessions;
+            } else {
+                self.previous_window_energy_wh = 0;
+                self.previous_window_sessions = 0;
+            }
+            self.current_window_energy_wh = 0;
+            self.current_window_sessions = 0;
+            self.tier_window = window;
+        }
+        self.current_window_energy_wh = self
+            .current_window_energy_wh
+            .checked_add(energy_wh)
+            .ok_or(DechargeError::NumericalOverflow)?;
+        self.current_window_sessions = self
+            .current_window_sessions
+            .checked_add(1)
+            .ok_or(DechargeError::NumericalOverflow)?;
+        Ok(())
+    }
+
+    pub fn trailing_energy_wh(&self) -> u64 {
+        self.current_window_energy_wh
+            .saturating_add(self.previous_window_energy_wh)
+    }
+
+    pub fn trailing_sessions(&self) -> u64 {
+        self.current_window_sessions
+            .saturating_add(self.previous_window_sessions)
+    }
+
+    pub fn credit_points(&mut self, epoch: u64, amount: u64) -> Result<()> {
+        self.outstanding_points = self
+            .outstanding_points
+            .checked_add(amount)
+            .ok_or(DechargeError::NumericalOverflow)?;
+        if amount == 0 {
+            return Ok(());
+        }
+
+        if let Some(bucket) = self
+            .point_buckets
+            .iter_mut()
+            .find(|bucket| bucket.amount > 0 && bucket.epoch == epoch)
+        {
+            bucket.amount = bucket
+                .amount
+                .checked_add(amount)
+                .ok_or(DechargeError::NumericalOverflow)?;
+            return Ok(());
+        }
+
+        let slot = match self.point_buckets.iter().position(|bucket| bucket.amount == 0) {
+            Some(slot) => slot,
+            None => self.merge_oldest_buckets()?,
+        };
+        self.point_buckets[slot] = PointBucket { epoch, amount };
+        Ok(())
+    }
+
+    /// Removes points from the oldest buckets first.
+    pub fn debit_points(&mut self, amount: u64) -> Result<()> {
+        self.outstanding_points = self
+            .outstanding_points
+            .checked_sub(amount)
+            .ok_or(DechargeError::InsufficientPoints)?;
+
+        let mut remaining = amount;
+        while remaining > 0 {
+            let Some(slot) = self.oldest_bucket() else {
+                break;
+            };
+            let taken = remaining.min(self.point_buckets[slot].amount);
+            self.point_buckets[slot].amount -= taken;
+            remaining -= taken;
+        }
+        Ok(())
+    }
+
+    /// Removes up to `max_amount` points earned before `cutoff_epoch`, oldest first,
+    /// and returns the expired amount.
+    pub fn take_expired_points(&mut self, cutoff_epoch: u64, max_amount: u64) -> Result<u64> {
+        let mut expired: u64 = 0;
+        while expired < max_amount {
+            let Some(slot) = self.oldest_bucket() else {
+                break;
+            };
+            let bucket = &mut self.point_buckets[slot];
+            if bucket.epoch >= cutoff_epoch {
+                break;
+            }
+            let taken = bucket.amount.min(max_amount - expired);
+            bucket.amount -= taken;
+            expired += taken;
+        }
+        self.outstanding_points = self.outstanding_points.saturating_sub(expired);
+        Ok(expired)
+    }
+
+    fn oldest_bucket(&self) -> Option<usize> {
+        self.point_buckets
+            .iter()
+            .enumerate()
+            .filter(|(_, bucket)| bucket.amount > 0)
+            .min_by_key(|(_, bucket)| bucket.epoch)
+            .map(|(slot, _)| slot)
+    }
+
+    /// Folds the second oldest bucket into the oldest, returning the freed slot.
+    /// Merged points take the older epoch so they never outlive their expiry.
+    fn merge_oldest_buckets(&mut self) -> Result<usize> {
+        let oldest = self.oldest_bucket().ok_or(DechargeError::NumericalOverflow)?;
+        let next = self
+            .point_buckets
+            .iter()
+            .enumerate()
+            .filter(|(slot, bucket)| *slot != oldest && bucket.amount > 0)
+            .min_by_key(|(_, bucket)| bucket.epoch)
+            .map(|(slot, _)| slot)
+            .ok_or(DechargeError::NumericalOverflow)?;
+        let amount = self.point_buckets[next].amount;
+        self.point_buckets[next].amount = 0;
+        self.point_buckets[oldest].amount = self.point_buckets[oldest]
+            .amount
+            .checked_add(amount)
+            .ok_or(DechargeError::NumericalOverflow)?;
+        Ok(next)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LoyaltyTier {
+    /// Trailing energy that qualifies for the tier, or zero to ignore energy.
+    pub min_energy_wh: u64,
+    /// Trailing session count that qualifies for the tier, or zero to ignore sessions.
+    pub min_sessions: u64,
+    /// Points multiplier; zero disables the tier.
+    pub multiplier_bps: u16,
+}
+
+impl LoyaltyTier {
+    pub const LEN: usize = 8 + 8 + 2;
+
+    pub fn qualifies(&self, energy_wh: u64, sessions: u64) -> bool {
+        self.multiplier_bps > 0
+            && ((self.min_energy_wh > 0 && energy_wh >= self.min_energy_wh)
+                || (self.min_sessions > 0 && sessions >= self.min_sessions))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PointBucket {
+    pub epoch: u64,
+    pub amount: u64,
+}
+
+impl PointBucket {
+    pub const LEN: usize = 8 + 8;
 }
 
 #[account]
@@ -74,10 +508,162 @@ pub struct ChargingSession {
     pub opened_at: i64,
     pub closed_at: i64,
     pub bump: u8,
+    /// `DriverDelegate` that opened the session, or default if the driver signed directly.
+    pub delegate: Pubkey,
+    pub stop_requested: bool,
+    /// Vehicle plugged in for this session, or default if none was declared.
+    pub vehicle: Pubkey,
+    pub start_soc_percent: Option<u8>,
+    pub end_soc_percent: Option<u8>,
+    pub target_soc_percent: Option<u8>,
+    /// Request a stop as soon as telemetry reports the target state of charge.
+    pub auto_close_at_target: bool,
 }
 
 impl ChargingSession {
-    pub const LEN: usize = 8 + 32 + 32 + (8 * 5) + SessionStatus::LEN + 32 + (8 * 2) + 1;
+    pub const LEN: usize =
+        8 + 32 + 32 + (8 * 5) + SessionStatus::LEN + 32 + (8 * 2) + 1 + 32 + 1 + 32 + (2 * 3) + 1;
+
+    /// Records a state-of-charge reading and returns whether the target has been reached.
+    pub fn record_soc(&mut self, soc_percent: u8) -> Result<bool> {
+        require!(soc_percent <= 100, DechargeError::InvalidTelemetry);
+        if self.start_soc_percent.is_none() {
+            self.start_soc_percent = Some(soc_percent);
+        }
+        self.end_soc_percent = Some(soc_percent);
+        Ok(self
+            .target_soc_percent
+            .is_some_and(|target| soc_percent >= target))
+    }
+}
+
+/// Receipt data for a closed session, keyed by the session address; the driver holds
+/// the matching non-transferable receipt token minted from `mint`.
+#[account]
+pub struct SessionReceipt {
+    pub session: Pubkey,
+    pub mint: Pubkey,
+    pub driver: Pubkey,
+    pub station: Pubkey,
+    pub energy_wh: u64,
+    pub price_microunits: u64,
+    pub points_earned: u64,
+    pub telemetry_hash: [u8; 32],
+    pub closed_at: i64,
+    pub bump: u8,
+}
+
+impl SessionReceipt {
+    pub const LEN: usize = 8 + (32 * 4) + (8 * 3) + 32 + 8 + 1;
+}
+
+#[account]
+pub struct Vehicle {
+    pub owner: Pubkey,
+    pub vin_hash: [u8; 32],
+    pub battery_capacity_wh: u64,
+    pub max_ac_kw: u32,
+    pub max_dc_kw: u32,
+    pub connector_types: u8,
+    pub bump: u8,
+}
+
+impl Vehicle {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 4 + 4 + 1 + 1;
+
+    pub fn max_power_watts(&self) -> u64 {
+        u64::from(self.max_ac_kw.max(self.max_dc_kw)) * 1_000
+    }
+
+    pub fn validate_telemetry(&self, energy_wh: u64, power_watts: u64) -> Result<()> {
+        require!(
+            energy_wh <= self.battery_capacity_wh,
+            DechargeError::EnergyExceedsBattery
+        );
+        require!(
+            power_watts <= self.max_power_watts(),
+            DechargeError::PowerExceedsVehicle
+        );
+        Ok(())
+    }
+
+    /// Validates energy delivered over `seconds` against the battery and average power limits.
+    pub fn validate_delivery(&self, energy_wh: u64, delivered_wh: u64, seconds: u64) -> Result<()> {
+        let average_power_watts = match seconds {
+            0 => 0,
+            seconds => {
+                delivered_wh
+                    .checked_mul(3_600)
+                    .ok_or(DechargeError::NumericalOverflow)?
+                    / seconds
+            }
+        };
+        self.validate_telemetry(energy_wh, average_power_watts)
+    }
+}
+
+#[zero_copy]
+#[derive(Default)]
+pub struct TelemetrySample {
+    pub timestamp: i64,
+    pub cumulative_wh: u64,
+    pub power_watts: u32,
+    pub soc_percent: u8,
+    pub _padding: [u8; 3],
+}
+
+/// Ring buffer of the most recent telemetry samples for a session.
+#[account(zero_copy)]
+pub struct SessionTelemetryLog {
+    pub session: Pubkey,
+    pub head: u32,
+    pub count: u32,
+    pub bump: u8,
+    pub _padding: [u8; 7],
+    pub samples: [TelemetrySample; TELEMETRY_LOG_CAPACITY],
+}
+
+impl SessionTelemetryLog {
+    pub const LEN: usize =
+        8 + 32 + 4 + 4 + 1 + 7 + TELEMETRY_LOG_CAPACITY * std::mem::size_of::<TelemetrySample>();
+
+    pub fn latest(&self) -> Option<&TelemetrySample> {
+        if self.count == 0 {
+            return None;
+        }
+        let index = (self.head as usize + TELEMETRY_LOG_CAPACITY - 1) % TELEMETRY_LOG_CAPACITY;
+        Some(&self.samples[index])
+    }
+
+    pub fn push(&mut self, sample: TelemetrySample) {
+        self.samples[self.head as usize] = sample;
+        self.head = ((self.head as usize + 1) % TELEMETRY_LOG_CAPACITY) as u32;
+        if (self.count as usize) < TELEMETRY_LOG_CAPACITY {
+            self.count += 1;
+        }
+    }
+}
+
+#[account]
+pub struct DriverDelegate {
+    pub driver: Pubkey,
+    /// Session key allowed to act for the driver, typically held by a mobile app.
+    pub delegate: Pubkey,
+    pub scopes: u8,
+    pub max_spend_microunits: u64,
+    pub spent_microunits: u64,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl DriverDelegate {
+    pub const LEN: usize = 8 + 32 + 32 + 1 + (8 * 3) + 1;
+
+    pub fn authorize(&self, scope: u8, now: i64) -> Result<()> {
+        require!(now < self.expires_at, DechargeError::DelegateExpired);
+        require!(self.scopes & scope == scope, DechargeError::DelegateScopeDenied);
+        Ok(())
+    }
 }
 
 #[account]
@@ -91,6 +677,245 @@ impl PointsVault {
     pub const LEN: usize = 8 + 32 + 32 + 1;
 }
 
+#[account]
+pub struct CatalogItem {
+    pub platform: Pubkey,
+    pub name: [u8; MAX_NAME_LEN],
+    pub points_cost: u64,
+    pub inventory: u32,
+    pub total_redeemed: u32,
+    /// Maximum redemptions per wallet, or zero for no limit.
+    pub per_wallet_limit: u32,
+    pub starts_at: i64,
+    /// End of the redemption window, or zero if open-ended.
+    pub ends_at: i64,
+    pub active: bool,
+    pub bump: u8,
+}
+
+impl CatalogItem {
+    pub const LEN: usize = 8 + 32 + MAX_NAME_LEN + 8 + (4 * 3) + (8 * 2) + 1 + 1;
+
+    pub fn has_valid_window(&self) -> bool {
+        self.ends_at == 0 || self.starts_at < self.ends_at
+    }
+
+    pub fn is_available(&self, now: i64) -> bool {
+        self.active && now >= self.starts_at && (self.ends_at == 0 || now < self.ends_at)
+    }
+}
+
+#[account]
+pub struct ItemRedemption {
+    pub item: Pubkey,
+    pub holder: Pubkey,
+    pub count: u32,
+    pub bump: u8,
+}
+
+impl ItemRedemption {
+    pub const LEN: usize = 8 + 32 + 32 + 4 + 1;
+}
+
+/// Global plot supply for the virtual world.
+#[account]
+pub struct WorldConfig {
+    pub platform: Pubkey,
+    pub max_plots: u32,
+    pub defined_plots: u32,
+    pub claimed_plots: u32,
+    pub bump: u8,
+    /// Cumulative charger installation price in lamports for levels 1 through 3.
+    pub level_prices_lamports: [u64; MAX_PLOT_LEVEL as usize],
+    /// Per-`BoostKind` purchase price and bonus, indexed by `BoostKind::index`.
+    pub boost_prices_lamports: [u64; BOOST_KIND_COUNT],
+    pub boost_bps: [u16; BOOST_KIND_COUNT],
+    /// Lifetime of one boost purchase; buying again while active extends it.
+    pub boost_duration_seconds: i64,
+    /// UTC hours during which Community Boost applies; wraps past midnight if start > end.
+    pub peak_start_hour: u8,
+    pub peak_end_hour: u8,
+    /// Session and passive reward multiplier for charger levels 1 through 3; fixed at
+    /// initialization because unsettled passive rewards are priced at the current value.
+    pub level_multiplier_bps: [u32; MAX_PLOT_LEVEL as usize],
+    /// Share of virtual session points minted to the plot owner.
+    pub owner_revenue_bps: u16,
+    /// Passive plot reward per slot per day, before any halvings; fixed at initialization.
+    pub plot_reward_points_per_slot_day: u64,
+    pub reward_schedule_start: i64,
+    /// Interval after which the passive reward rate halves; zero keeps it flat.
+    pub reward_halving_seconds: i64,
+    /// Inclusive map bounds every region must fit inside.
+    pub min_x: i32,
+    pub min_y: i32,
+    pub max_x: i32,
+    pub max_y: i32,
+}
+
+impl WorldConfig {
+    pub const LEN: usize = 8 + 32 + (4 * 3) + 1 + (8 * MAX_PLOT_LEVEL as usize)
+        + (8 * BOOST_KIND_COUNT) + (2 * BOOST_KIND_COUNT) + 8 + 1 + 1
+        + (4 * MAX_PLOT_LEVEL as usize) + 2 + (8 * 3) + (4 * 4);
+
+    pub fn contains_region(&self, region: &Region) -> bool {
+        self.min_x <= region.min_x
+            && region.max_x <= self.max_x
+            && self.min_y <= region.min_y
+            && region.max_y <= self.max_y
+    }
+
+    /// Integrates a per-day rate over `[from, to)` under the halving schedule,
+    /// returning point-seconds per day.
+    pub fn scheduled_reward(&self, rate_per_day: u64, mut from: i64, to: i64) -> u128 {
+        if self.reward_halving_seconds <= 0 {
+            return u128::from(rate_per_day) * to.saturating_sub(from).max(0) as u128;
+        }
+
+        let mut total = 0u128;
+        while from < to {
+            let halvings = from.saturating_sub(self.reward_schedule_start).max(0)
+                / self.reward_halving_seconds;
+            let rate = rate_per_day
+                .checked_shr(u32::try_from(halvings).unwrap_or(u32::MAX))
+                .unwrap_or_default();
+            if rate == 0 {
+                break;
+            }
+            let period_end = self
+                .reward_schedule_start
+                .saturating_add(self.reward_halving_seconds.saturating_mul(halvings + 1));
+            let segment_end = to.min(period_end);
+            total += u128::from(rate) * (segment_end - from) as u128;
+            from = segment_end;
+        }
+        total
+    }
+
+    pub fn level_multiplier(&self, upgrade_level: u8) -> u32 {
+        match upgrade_level {
+            0 => 0,
+            level => self.level_multiplier_bps[usize::from(level.min(MAX_PLOT_LEVEL)) - 1],
+        }
+    }
+
+    /// Points earned by a virtual session before the owner's revenue share is taken.
+    pub fn virtual_session_points(
+        &self,
+        energy_wh: u64,
+        point_rate_microunits: u64,
+        upgrade_level: u8,
+        boost_bps: u64,
+    ) -> Result<u64> {
+        let level_bps = self.level_multiplier(upgrade_level);
+        let scaled = u128::from(energy_wh)
+            .checked_mul(u128::from(point_rate_microunits))
+            .and_then(|value| value.checked_mul(u128::from(level_bps)))
+            .and_then(|value| value.checked_mul(u128::from(BPS_DENOMINATOR + boost_bps)))
+            .ok_or(DechargeError::NumericalOverflow)?;
+        let unit = u128::from(MICROS_PER_POINT)
+            * u128::from(BPS_DENOMINATOR)
+            * u128::from(BPS_DENOMINATOR);
+        u64::try_from(scaled / unit).map_err(|_| error!(DechargeError::NumericalOverflow))
+    }
+
+    pub fn owner_revenue(&self, session_points: u64) -> u64 {
+        (u128::from(session_points) * u128::from(self.owner_revenue_bps)
+            / u128::from(BPS_DENOMINATOR)) as u64
+    }
+
+    pub fn is_peak_hour(&self, now: i64) -> bool {
+        let hour = (now.rem_euclid(SECONDS_PER_DAY) / SECONDS_PER_HOUR) as u8;
+        if self.peak_start_hour <= self.peak_end_hour {
+            (self.peak_start_hour..self.peak_end_hour).contains(&hour)
+        } else {
+            hour >= self.peak_start_hour || hour < self.peak_end_hour
+        }
+    }
+
+    /// Cumulative level prices must strictly increase so every upgrade costs something.
+    pub fn level_prices_ascending(prices: &[u64; MAX_PLOT_LEVEL as usize]) -> bool {
+        prices.windows(2).all(|pair| pair[0] < pair[1])
+    }
+
+    /// Price of moving a plot from `from_level` to `to_level`; level zero is free.
+    pub fn upgrade_price(&self, from_level: u8, to_level: u8) -> Option<u64> {
+        let price = |level: u8| match level {
+            0 => 0,
+            level => self.level_prices_lamports[usize::from(level) - 1],
+        };
+        price(to_level).checked_sub(price(from_level))
+    }
+}
+
+/// Admin-defined area of the world map holding a fixed number of plot slots.
+/// Plots are keyed by coordinate alone, so where regions overlap each coordinate
+/// can still be defined only once.
+#[account]
+pub struct Region {
+    pub key: [u8; MAX_NAME_LEN],
+    pub min_x: i32,
+    pub min_y: i32,
+    pub max_x: i32,
+    pub max_y: i32,
+    pub max_plots: u32,
+    pub defined_plots: u32,
+    pub claimed_plots: u32,
+    pub base_reward_points: u64,
+    pub bump: u8,
+}
+
+impl Region {
+    pub const LEN: usize = 8 + MAX_NAME_LEN + (4 * 4) + (4 * 3) + 8 + 1;
+
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum BoostKind {
+    /// Extra points on every virtual session.
+    SolarArray,
+    /// Extra plot slot capacity.
+    GridSync,
+    /// Extra points on virtual sessions during peak hours.
+    CommunityBoost,
+}
+
+impl BoostKind {
+    pub const LEN: usize = 1;
+
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// Time-limited bonus attached to a `WorldPlot`; one account per plot and kind.
+#[account]
+pub struct PlotBoost {
+    pub plot: Pubkey,
+    pub kind: BoostKind,
+    pub magnitude_bps: u16,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl PlotBoost {
+    pub const LEN: usize = 8 + 32 + BoostKind::LEN + 2 + 8 + 1;
+
+    /// Bonus this boost grants at `now`, honouring expiry and peak hours.
+    pub fn bonus_bps(&self, world_config: &WorldConfig, now: i64) -> u64 {
+        let applies = now < self.expires_at
+            && (self.kind != BoostKind::CommunityBoost || world_config.is_peak_hour(now));
+        if applies {
+            u64::from(self.magnitude_bps)
+        } else {
+            0
+        }
+    }
+}
+
+/// Plot slot defined by the admin; unclaimed while `owner` is the default key.
 #[account]
 pub struct WorldPlot {
     pub owner: Pubkey,
@@ -99,10 +924,179 @@ pub struct WorldPlot {
     pub upgrade_level: u8,
     pub last_reward_time: i64,
     pub bump: u8,
+    pub region: Pubkey,
+    pub x: i32,
+    pub y: i32,
+    pub virtual_sessions: u64,
+    pub virtual_energy_wh: u64,
+    pub owner_points_earned: u64,
+    /// Rewards and revenue share held back by the emission budget, paid on the next claim.
+    pub deferred_reward_points: u64,
 }
 
 impl WorldPlot {
-    pub const LEN: usize = 8 + 32 + MAX_NAME_LEN + 4 + 1 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + MAX_NAME_LEN + 4 + 1 + 8 + 1 + 32 + (4 * 2) + (8 * 3) + 8;
+
+    /// Slot capacity in basis points of a slot, including a Grid Sync boost active at `at`.
+    pub fn capacity_bps(
+        &self,
+        world_config: &WorldConfig,
+        grid_sync: Option<&PlotBoost>,
+        at: i64,
+    ) -> u64 {
+        let bonus_bps = grid_sync.map_or(0, |boost| boost.bonus_bps(world_config, at));
+        u64::from(self.slot_capacity) * (BPS_DENOMINATOR + bonus_bps)
+    }
+
+    /// Passive rewards accrued between `last_reward_time` and `now`, paid per slot of
+    /// capacity; Grid Sync capacity only counts up to the boost's expiry.
+    pub fn accrued_rewards(
+        &self,
+        world_config: &WorldConfig,
+        region: &Region,
+        grid_sync: Option<&PlotBoost>,
+        now: i64,
+    ) -> Result<u64> {
+        let rate_per_day = world_config
+            .plot_reward_points_per_slot_day
+            .checked_add(region.base_reward_points)
+            .ok_or(DechargeError::NumericalOverflow)?;
+        let from = self.last_reward_time;
+        let boost_end = grid_sync.map_or(from, |boost| boost.expires_at.clamp(from, now.max(from)));
+        let boosted = world_config
+            .scheduled_reward(rate_per_day, from, boost_end)
+            .checked_mul(u128::from(self.capacity_bps(world_config, grid_sync, from)));
+        let unboosted = world_config
+            .scheduled_reward(rate_per_day, boost_end, now)
+            .checked_mul(u128::from(self.capacity_bps(world_config, grid_sync, boost_end)));
+        let scaled = boosted
+            .zip(unboosted)
+            .and_then(|(boosted, unboosted)| boosted.checked_add(unboosted))
+            .and_then(|value| {
+                value.checked_mul(u128::from(world_config.level_multiplier(self.upgrade_level)))
+            })
+            .ok_or(DechargeError::NumericalOverflow)?;
+        let unit = u128::from(BPS_DENOMINATOR).pow(2) * SECONDS_PER_DAY as u128;
+        u64::try_from(scaled / unit).map_err(|_| error!(DechargeError::NumericalOverflow))
+    }
+
+    /// Moves rewards accrued up to `now` into `deferred_reward_points`, so a level or
+    /// boost change only affects accrual from `now` on.
+    pub fn settle_rewards(
+        &mut self,
+        world_config: &WorldConfig,
+        region: &Region,
+        grid_sync: Option<&PlotBoost>,
+        now: i64,
+    ) -> Result<()> {
+        let accrued = self.accrued_rewards(world_config, region, grid_sync, now)?;
+        self.deferred_reward_points = self
+            .deferred_reward_points
+            .checked_add(accrued)
+            .ok_or(DechargeError::NumericalOverflow)?;
+        self.last_reward_time = now;
+        Ok(())
+    }
+}
+
+/// Replay guard for one oracle-reported virtual session on a plot.
+#[account]
+pub struct VirtualSessionRecord {
+    pub plot: Pubkey,
+    pub session_id: [u8; 32],
+    pub energy_wh: u64,
+    pub owner_points: u64,
+    pub recorded_at: i64,
+    pub bump: u8,
+}
+
+impl VirtualSessionRecord {
+    pub const LEN: usize = 8 + 32 + 32 + (8 * 3) + 1;
+}
+
+/// Point tokens locked by a holder in exchange for voting power.
+#[account]
+pub struct PointStake {
+    pub owner: Pubkey,
+    pub token_account: Pubkey,
+    pub amount: u64,
+    pub unlock_at: i64,
+    pub bump: u8,
+}
+
+impl PointStake {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 1;
+
+    /// Staked amount weighted by the lock time left, relative to the maximum lock.
+    pub fn voting_power(&self, now: i64) -> u64 {
+        let remaining = self.unlock_at.saturating_sub(now).clamp(0, MAX_STAKE_LOCK_SECONDS);
+        let power = u128::from(self.amount) * remaining as u128 / MAX_STAKE_LOCK_SECONDS as u128;
+        u64::try_from(power).unwrap_or(u64::MAX)
+    }
+}
+
+/// `PlatformConfig` parameters that governance proposals may change.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum GovernanceParam {
+    PointRateMicrounits,
+    IceBaselineGPerKwh,
+    ReferrerBonusPoints,
+    RefereeBonusPoints,
+    DailyTransferLimit,
+}
+
+impl GovernanceParam {
+    pub const LEN: usize = 1;
+
+    pub fn apply(&self, config: &mut PlatformConfig, value: u64) -> Result<()> {
+        match self {
+            Self::PointRateMicrounits => config.point_rate_microunits = value,
+            Self::IceBaselineGPerKwh => {
+                config.ice_baseline_g_per_kwh =
+                    u32::try_from(value).map_err(|_| error!(DechargeError::NumericalOverflow))?
+            }
+            Self::ReferrerBonusPoints => config.referrer_bonus_points = value,
+            Self::RefereeBonusPoints => config.referee_bonus_points = value,
+            Self::DailyTransferLimit => config.daily_transfer_limit = value,
+        }
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Proposal {
+    pub proposer: Pubkey,
+    pub id: u64,
+    pub param: GovernanceParam,
+    pub value: u64,
+    pub voting_ends_at: i64,
+    /// Quorum in force when the proposal was created.
+    pub quorum_power: u64,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub executed: bool,
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub const LEN: usize = 8 + 32 + 8 + GovernanceParam::LEN + 8 + 8 + (8 * 3) + 1 + 1;
+
+    pub fn passed(&self) -> bool {
+        self.votes_for >= self.quorum_power && self.votes_for > self.votes_against
+    }
+}
+
+#[account]
+pub struct VoteRecord {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub support: bool,
+    pub power: u64,
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 1;
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
@@ -123,3 +1117,479 @@ pub fn fit_to_array<const N: usize>(input: &str) -> [u8; N] {
     buffer[..copy_len].copy_from_slice(&bytes[..copy_len]);
     buffer
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn telemetry_sample(timestamp: i64) -> TelemetrySample {
+        TelemetrySample {
+            timestamp,
+            ..TelemetrySample::default()
+        }
+    }
+
+    fn charging_station(
+        grid_carbon_g_per_kwh: u32,
+        renewable_fraction_bps: u16,
+    ) -> ChargingStation {
+        ChargingStation {
+            platform: Pubkey::default(),
+            operator: Pubkey::default(),
+            city: [0; MAX_CITY_LEN],
+            name: [0; MAX_NAME_LEN],
+            latitude_micro: 0,
+            longitude_micro: 0,
+            max_kw: 0,
+            active: true,
+            pricing_energy_microunits: 0,
+            pricing_time_microunits: 0,
+            uri: [0; MAX_URI_LEN],
+            bump: 0,
+            next_session: 0,
+            grid_carbon_g_per_kwh,
+            renewable_fraction_bps,
+            total_energy_wh: 0,
+            total_co2_avoided_g: 0,
+            connector_types: 0,
+        }
+    }
+
+    fn platform_config() -> PlatformConfig {
+        PlatformConfig {
+            admin: Pubkey::default(),
+            oracle: Pubkey::default(),
+            point_mint: Pubkey::default(),
+            bump: 0,
+            point_rate_microunits: 0,
+            payment_treasury: Pubkey::default(),
+            world_treasury: Pubkey::default(),
+            ice_baseline_g_per_kwh: 0,
+            rec_mint: Pubkey::default(),
+            points_epoch_seconds: 0,
+            points_expiry_epochs: 0,
+            loyalty_tiers: [LoyaltyTier::default(); MAX_LOYALTY_TIERS],
+            tier_window_seconds: 0,
+            referral_threshold_wh: 0,
+            referrer_bonus_points: 0,
+            referee_bonus_points: 0,
+            max_referrals_per_referrer: 0,
+            epoch_emission_budget: 0,
+            lifetime_emission_budget: 0,
+            emission_warning_bps: 0,
+            daily_transfer_limit: 0,
+            governance_quorum_power: 0,
+            governance_voting_seconds: 0,
+            next_proposal: 0,
+            plot_claim_fee_lamports: 0,
+            plot_claim_fee_points: 0,
+            points_epoch_start: 0,
+        }
+    }
+
+    fn driver_profile() -> DriverProfile {
+        DriverProfile {
+            driver: Pubkey::default(),
+            total_sessions: 0,
+            total_energy_wh: 0,
+            total_points_earned: 0,
+            outstanding_points: 0,
+            bump: 0,
+            total_co2_avoided_g: 0,
+            point_buckets: [PointBucket::default(); MAX_POINT_BUCKETS],
+            tier: 0,
+            tier_window: 0,
+            current_window_energy_wh: 0,
+            current_window_sessions: 0,
+            previous_window_energy_wh: 0,
+            previous_window_sessions: 0,
+            referrer: Pubkey::default(),
+            referral_rewarded: false,
+            referrals_rewarded: 0,
+            point_remainder: 0,
+            deferred_points: 0,
+            transfer_day: 0,
+            transferred_today: 0,
+        }
+    }
+
+    fn bucket_amount(profile: &DriverProfile, epoch: u64) -> u64 {
+        profile
+            .point_buckets
+            .iter()
+            .filter(|bucket| bucket.amount > 0 && bucket.epoch == epoch)
+            .map(|bucket| bucket.amount)
+            .sum()
+    }
+
+    fn world_config(reward_halving_seconds: i64) -> WorldConfig {
+        WorldConfig {
+            platform: Pubkey::default(),
+            max_plots: 10,
+            defined_plots: 0,
+            claimed_plots: 0,
+            bump: 0,
+            level_prices_lamports: [100, 300, 600],
+            boost_prices_lamports: [0; BOOST_KIND_COUNT],
+            boost_bps: [0; BOOST_KIND_COUNT],
+            boost_duration_seconds: SECONDS_PER_DAY,
+            peak_start_hour: 0,
+            peak_end_hour: 0,
+            level_multiplier_bps: [10_000, 15_000, 20_000],
+            owner_revenue_bps: 0,
+            plot_reward_points_per_slot_day: 1_000,
+            reward_schedule_start: 0,
+            reward_halving_seconds,
+            min_x: -100,
+            min_y: -100,
+            max_x: 100,
+            max_y: 100,
+        }
+    }
+
+    fn region() -> Region {
+        Region {
+            key: [0; MAX_NAME_LEN],
+            min_x: 0,
+            min_y: 0,
+            max_x: 10,
+            max_y: 10,
+            max_plots: 10,
+            defined_plots: 0,
+            claimed_plots: 0,
+            base_reward_points: 0,
+            bump: 0,
+        }
+    }
+
+    fn world_plot() -> WorldPlot {
+        WorldPlot {
+            owner: Pubkey::default(),
+            region_key: [0; MAX_NAME_LEN],
+            slot_capacity: 1,
+            upgrade_level: 1,
+            last_reward_time: 0,
+            bump: 0,
+            region: Pubkey::default(),
+            x: 0,
+            y: 0,
+            virtual_sessions: 0,
+            virtual_energy_wh: 0,
+            owner_points_earned: 0,
+            deferred_reward_points: 0,
+        }
+    }
+
+    fn grid_sync(magnitude_bps: u16, expires_at: i64) -> PlotBoost {
+        PlotBoost {
+            plot: Pubkey::default(),
+            kind: BoostKind::GridSync,
+            magnitude_bps,
+            expires_at,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn telemetry_log_overwrites_the_oldest_sample_when_full() {
+        let mut log = SessionTelemetryLog {
+            session: Pubkey::default(),
+            head: 0,
+            count: 0,
+            bump: 0,
+            _padding: [0; 7],
+            samples: [TelemetrySample::default(); TELEMETRY_LOG_CAPACITY],
+        };
+        assert!(log.latest().is_none());
+
+        log.push(telemetry_sample(1));
+        assert_eq!(log.latest().unwrap().timestamp, 1);
+
+        for timestamp in 2..=TELEMETRY_LOG_CAPACITY as i64 + 2 {
+            log.push(telemetry_sample(timestamp));
+        }
+        assert_eq!(log.count as usize, TELEMETRY_LOG_CAPACITY);
+        assert_eq!(log.head, 2);
+        assert_eq!(
+            log.latest().unwrap().timestamp,
+            TELEMETRY_LOG_CAPACITY as i64 + 2
+        );
+        assert_eq!(log.samples[0].timestamp, TELEMETRY_LOG_CAPACITY as i64 + 1);
+        assert_eq!(log.samples[2].timestamp, 3);
+    }
+
+    #[test]
+    fn co2_avoided_nets_the_grid_share_against_the_baseline() {
+        assert_eq!(
+            charging_station(400, 5_000)
+                .co2_avoided_g(10_000, 800)
+                .unwrap(),
+            6_000
+        );
+        assert_eq!(
+            charging_station(400, 10_000)
+                .co2_avoided_g(10_000, 800)
+                .unwrap(),
+            8_000
+        );
+        assert_eq!(
+            charging_station(1_000, 0)
+                .co2_avoided_g(10_000, 800)
+                .unwrap(),
+            0
+        );
+        assert!(charging_station(400, 10_001)
+            .co2_avoided_g(10_000, 800)
+            .is_err());
+    }
+
+    #[test]
+    fn points_epochs_count_from_when_expiry_was_enabled() {
+        let mut config = platform_config();
+        assert_eq!(config.points_epoch(5_000), 0);
+        assert_eq!(config.points_expiry_cutoff(5_000), None);
+
+        config.points_epoch_seconds = 100;
+        config.points_expiry_epochs = 2;
+        config.points_epoch_start = 1_000;
+        assert_eq!(config.points_epoch(500), 1);
+        assert_eq!(config.points_epoch(1_099), 1);
+        assert_eq!(config.points_epoch(1_100), 2);
+        assert_eq!(config.points_expiry_cutoff(1_100), None);
+        assert_eq!(config.points_expiry_cutoff(1_200), Some(2));
+    }
+
+    #[test]
+    fn credit_points_merges_into_the_older_epoch_when_full() {
+        let mut profile = driver_profile();
+        for epoch in 1..=MAX_POINT_BUCKETS as u64 {
+            profile.credit_points(epoch, 10).unwrap();
+        }
+        profile.credit_points(1, 5).unwrap();
+        assert_eq!(bucket_amount(&profile, 1), 15);
+
+        profile.credit_points(100, 7).unwrap();
+        assert_eq!(bucket_amount(&profile, 1), 25);
+        assert_eq!(bucket_amount(&profile, 2), 0);
+        assert_eq!(bucket_amount(&profile, 100), 7);
+        assert_eq!(
+            profile.outstanding_points,
+            10 * MAX_POINT_BUCKETS as u64 + 12
+        );
+    }
+
+    #[test]
+    fn debit_points_takes_the_oldest_buckets_first() {
+        let mut profile = driver_profile();
+        profile.credit_points(2, 10).unwrap();
+        profile.credit_points(1, 10).unwrap();
+
+        profile.debit_points(15).unwrap();
+        assert_eq!(bucket_amount(&profile, 1), 0);
+        assert_eq!(bucket_amount(&profile, 2), 5);
+        assert_eq!(profile.outstanding_points, 5);
+        assert!(profile.debit_points(6).is_err());
+    }
+
+    #[test]
+    fn take_expired_points_stops_at_the_cutoff_and_the_cap() {
+        let mut profile = driver_profile();
+        profile.credit_points(1, 10).unwrap();
+        profile.credit_points(2, 10).unwrap();
+        profile.credit_points(5, 10).unwrap();
+
+        assert_eq!(profile.take_expired_points(3, 15).unwrap(), 15);
+        assert_eq!(bucket_amount(&profile, 1), 0);
+        assert_eq!(bucket_amount(&profile, 2), 5);
+        assert_eq!(profile.take_expired_points(3, 100).unwrap(), 5);
+        assert_eq!(bucket_amount(&profile, 5), 10);
+        assert_eq!(profile.outstanding_points, 10);
+    }
+
+    #[test]
+    fn loyalty_tier_qualifies_on_energy_or_sessions() {
+        let tier = LoyaltyTier {
+            min_energy_wh: 1_000,
+            min_sessions: 5,
+            multiplier_bps: 12_000,
+        };
+        assert!(tier.qualifies(1_000, 0));
+        assert!(tier.qualifies(0, 5));
+        assert!(!tier.qualifies(999, 4));
+
+        let disabled = LoyaltyTier {
+            multiplier_bps: 0,
+            ..tier
+        };
+        assert!(!disabled.qualifies(1_000, 5));
+        let sessions_only = LoyaltyTier {
+            min_energy_wh: 0,
+            ..tier
+        };
+        assert!(!sessions_only.qualifies(u64::MAX, 4));
+    }
+
+    #[test]
+    fn record_tier_activity_rolls_the_trailing_window() {
+        let mut profile = driver_profile();
+        profile.record_tier_activity(1, 100).unwrap();
+        profile.record_tier_activity(1, 50).unwrap();
+        assert_eq!(profile.trailing_energy_wh(), 150);
+        assert_eq!(profile.trailing_sessions(), 2);
+
+        profile.record_tier_activity(2, 10).unwrap();
+        assert_eq!(profile.previous_window_energy_wh, 150);
+        assert_eq!(profile.current_window_energy_wh, 10);
+        assert_eq!(profile.trailing_energy_wh(), 160);
+        assert_eq!(profile.trailing_sessions(), 3);
+
+        profile.record_tier_activity(5, 1).unwrap();
+        assert_eq!(profile.trailing_energy_wh(), 1);
+        assert_eq!(profile.trailing_sessions(), 1);
+    }
+
+    #[test]
+    fn carry_points_keeps_the_fraction_for_the_next_call() {
+        let unit = u128::from(MICROS_PER_POINT) * u128::from(BPS_DENOMINATOR);
+        let mut profile = driver_profile();
+
+        assert_eq!(profile.carry_points(unit * 3 / 2).unwrap(), 1);
+        assert_eq!(u128::from(profile.point_remainder), unit / 2);
+        assert_eq!(profile.carry_points(unit / 2).unwrap(), 1);
+        assert_eq!(profile.point_remainder, 0);
+    }
+
+    #[test]
+    fn emissions_allocate_within_both_budgets_and_warn_near_the_cap() {
+        let mut config = platform_config();
+        config.epoch_emission_budget = 100;
+        config.lifetime_emission_budget = 150;
+        config.emission_warning_bps = 8_000;
+        let mut emissions = PointEmissions {
+            epoch: 1,
+            epoch_minted: 0,
+            lifetime_minted: 0,
+            bump: 0,
+        };
+
+        assert_eq!(emissions.allocate(&config, 60).unwrap(), 60);
+        assert!(!emissions.near_cap(&config));
+        assert_eq!(emissions.allocate(&config, 60).unwrap(), 40);
+        assert!(emissions.near_cap(&config));
+
+        emissions.roll_epoch(2);
+        assert_eq!(emissions.epoch_minted, 0);
+        assert_eq!(emissions.allocate(&config, 100).unwrap(), 50);
+        assert_eq!(emissions.lifetime_minted, 150);
+        assert_eq!(emissions.remaining(&config), 0);
+    }
+
+    #[test]
+    fn record_transfer_resets_each_day_and_enforces_the_limit() {
+        let mut profile = driver_profile();
+        profile.record_transfer(1, 60, 100).unwrap();
+        assert!(profile.record_transfer(1, 50, 100).is_err());
+
+        let mut profile = driver_profile();
+        profile.record_transfer(1, 60, 100).unwrap();
+        profile.record_transfer(2, 100, 100).unwrap();
+        assert_eq!(profile.transfer_day, 2);
+        assert_eq!(profile.transferred_today, 100);
+        profile.record_transfer(2, 1_000, 0).unwrap();
+    }
+
+    #[test]
+    fn voting_power_decays_with_the_remaining_lock() {
+        let stake = PointStake {
+            owner: Pubkey::default(),
+            token_account: Pubkey::default(),
+            amount: 1_000,
+            unlock_at: MAX_STAKE_LOCK_SECONDS,
+            bump: 0,
+        };
+        assert_eq!(stake.voting_power(0), 1_000);
+        assert_eq!(stake.voting_power(MAX_STAKE_LOCK_SECONDS / 2), 500);
+        assert_eq!(stake.voting_power(MAX_STAKE_LOCK_SECONDS), 0);
+        assert_eq!(stake.voting_power(MAX_STAKE_LOCK_SECONDS + 1), 0);
+
+        let overlong = PointStake {
+            unlock_at: 2 * MAX_STAKE_LOCK_SECONDS,
+            ..stake
+        };
+        assert_eq!(overlong.voting_power(0), 1_000);
+    }
+
+    #[test]
+    fn upgrade_price_charges_the_difference_between_levels() {
+        let world_config = world_config(0);
+        assert_eq!(world_config.upgrade_price(0, 2), Some(300));
+        assert_eq!(world_config.upgrade_price(1, 3), Some(500));
+        assert_eq!(world_config.upgrade_price(3, 1), None);
+        assert!(WorldConfig::level_prices_ascending(&[100, 300, 600]));
+        assert!(!WorldConfig::level_prices_ascending(&[100, 100, 600]));
+    }
+
+    #[test]
+    fn grid_sync_adds_slot_capacity_until_it_expires() {
+        let world_config = world_config(0);
+        let region = region();
+        let mut plot = world_plot();
+        plot.slot_capacity = 2;
+        let boost = grid_sync(1_000, SECONDS_PER_DAY);
+        let now = 2 * SECONDS_PER_DAY;
+
+        assert_eq!(plot.capacity_bps(&world_config, None, 0), 20_000);
+        assert_eq!(plot.capacity_bps(&world_config, Some(&boost), 0), 22_000);
+        assert_eq!(
+            plot.capacity_bps(&world_config, Some(&boost), SECONDS_PER_DAY),
+            20_000
+        );
+
+        assert_eq!(
+            plot.accrued_rewards(&world_config, &region, None, now)
+                .unwrap(),
+            4_000
+        );
+        assert_eq!(
+            plot.accrued_rewards(&world_config, &region, Some(&boost), now)
+                .unwrap(),
+            4_200
+        );
+    }
+
+    #[test]
+    fn scheduled_reward_halves_each_period() {
+        assert_eq!(world_config(0).scheduled_reward(8, 0, 200), 1_600);
+        assert_eq!(world_config(100).scheduled_reward(8, 0, 200), 1_200);
+        assert_eq!(
+            world_config(100).scheduled_reward(8, 150, 250),
+            4 * 50 + 2 * 50
+        );
+        assert_eq!(world_config(100).scheduled_reward(8, 200, 100), 0);
+    }
+
+    #[test]
+    fn settle_rewards_defers_accrued_points_at_the_current_level() {
+        let world_config = world_config(0);
+        let region = region();
+        let mut plot = world_plot();
+        let now = 2 * SECONDS_PER_DAY;
+
+        plot.settle_rewards(&world_config, &region, None, now)
+            .unwrap();
+        assert_eq!(plot.deferred_reward_points, 2_000);
+        assert_eq!(plot.last_reward_time, now);
+        assert_eq!(
+            plot.accrued_rewards(&world_config, &region, None, now)
+                .unwrap(),
+            0
+        );
+
+        plot.upgrade_level = 2;
+        assert_eq!(
+            plot.accrued_rewards(&world_config, &region, None, now + SECONDS_PER_DAY)
+                .unwrap(),
+            1_500
+        );
+    }
+}